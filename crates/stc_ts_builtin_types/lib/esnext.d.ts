@@ -3,3 +3,4 @@
 /// <reference lib="esnext.string" />
 /// <reference lib="esnext.promise" />
 /// <reference lib="esnext.weakref" />
+/// <reference lib="esnext.array" />
@@ -97,15 +97,16 @@ impl Lib {
             Self::EsnextString => 39,
             Self::EsnextPromise => 40,
             Self::EsnextWeakref => 41,
-            Self::Esnext => 42,
-            Self::Dom => 43,
-            Self::WebworkerImportscripts => 44,
-            Self::Scripthost => 45,
-            Self::DomIterable => 46,
-            Self::DomIterableGenerated => 47,
-            Self::Header => 48,
-            Self::WebworkerGenerated => 49,
-            Self::WebworkerIterableGenerated => 50,
+            Self::EsnextArray => 42,
+            Self::Esnext => 43,
+            Self::Dom => 44,
+            Self::WebworkerImportscripts => 45,
+            Self::Scripthost => 46,
+            Self::DomIterable => 47,
+            Self::DomIterableGenerated => 48,
+            Self::Header => 49,
+            Self::WebworkerGenerated => 50,
+            Self::WebworkerIterableGenerated => 51,
 
             Self::Es5Full => 100,
             Self::Es2015Full => 101,
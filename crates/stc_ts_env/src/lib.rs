@@ -201,4 +201,28 @@ pub struct Rule {
     pub no_unused_locals: bool,
     pub no_unused_parameters: bool,
     pub use_define_property_for_class_fields: bool,
+
+    /// Not a tsc option. If `true`, excess properties of object literals are
+    /// reported in type casts.
+    pub report_excess_properties_in_type_casts: bool,
+
+    /// Not a tsc option. If `true`, a note is emitted when a type cast changes
+    /// labels of tuple elements.
+    pub note_tuple_label_changes_in_type_casts: bool,
+
+    /// Not a tsc option. If `true`, a note is emitted when a readonly tuple is
+    /// casted to a mutable tuple.
+    pub note_readonly_removal_in_type_casts: bool,
+
+    /// Not a tsc option. If `true`, a note is emitted when a number literal is
+    /// casted to a bigint literal or vice versa.
+    pub note_number_bigint_casts: bool,
+
+    /// Not a tsc option. If `true`, a block guarded by a condition which
+    /// narrows a variable to `never` is reported as unreachable.
+    pub report_never_narrowed_blocks: bool,
+
+    /// Not a tsc option. If `true`, type casts between types which don't
+    /// overlap are reported as warnings instead of errors.
+    pub non_overlapping_type_casts_as_warnings: bool,
 }
@@ -146,6 +146,16 @@ pub enum Error {
     DefinedWitHAccessorInSuper {
         span: Span,
     },
+
+    /// TS2808
+    GetterLessAccessibleThanSetter {
+        span: Span,
+    },
+
+    /// TS2416
+    PropertyIncompatibleWithSuperClassMember {
+        span: Span,
+    },
     /// TS5048
     OptionInvalidForEs3 {
         span: Span,
@@ -377,6 +387,11 @@ pub enum Error {
         span: Span,
     },
 
+    /// TS18016
+    PrivateIdentifierOutsideClass {
+        span: Span,
+    },
+
     /// TS18011
     CannotDeletePrivateProperty {
         span: Span,
@@ -477,6 +492,11 @@ pub enum Error {
         span: Span,
     },
 
+    /// TS2717
+    SubsequentPropertyDeclarationMustHaveSameType {
+        span: Span,
+    },
+
     /// TS2661
     CannotExportNonLocalVar {
         span: Span,
@@ -510,6 +530,16 @@ pub enum Error {
         span: Span,
     },
 
+    /// TS2589
+    ExcessivelyDeepTypeInstantiation {
+        span: Span,
+    },
+
+    /// TS2422
+    ClassImplementsNonObjectType {
+        span: Span,
+    },
+
     /// TS2420
     InvalidImplOfInterface {
         span: Span,
@@ -545,6 +575,11 @@ pub enum Error {
         span: Span,
     },
 
+    /// TS2660
+    SuperOutsideClassOrObjectLit {
+        span: Span,
+    },
+
     GeneratorCannotHaveVoidAsReturnType {
         span: Span,
     },
@@ -657,6 +692,19 @@ pub enum Error {
         inner: Box<Error>,
     },
 
+    /// TS2345
+    ///
+    /// Same as [Error::WrongArgType], but the argument is an element of a
+    /// spread tuple with a label.
+    WrongArgTypeOfLabeledElement {
+        /// Span of the spread.
+        span: Span,
+
+        label: JsWord,
+
+        inner: Box<Error>,
+    },
+
     ImportFailed {
         span: Span,
         orig: Id,
@@ -680,6 +728,7 @@ pub enum Error {
         prop: Key,
     },
 
+    /// TS2558
     TypeParameterCountMismatch {
         span: Span,
         min: usize,
@@ -687,6 +736,14 @@ pub enum Error {
         actual: usize,
     },
 
+    /// TS2314 if `min == max`, otherwise TS2707
+    GenericTypeArgCountMismatch {
+        span: Span,
+        min: usize,
+        max: usize,
+        actual: usize,
+    },
+
     ParameterCountMismatch {
         span: Span,
         min: usize,
@@ -743,6 +800,37 @@ pub enum Error {
         right: Span,
     },
 
+    /// TS2352
+    ///
+    /// An array literal is casted to a shorter tuple. `excess` contains the
+    /// spans of literal elements which do not exist in the target.
+    TupleCastWithExcessElements {
+        span: Span,
+        excess: Vec<Span>,
+        source_len: usize,
+        target_len: usize,
+    },
+
+    /// Not a tsc error. This is emitted as a note.
+    TupleLabelChangedByCast {
+        span: Span,
+    },
+
+    /// Not a tsc error. This is emitted as a note.
+    NumberBigIntCast {
+        span: Span,
+    },
+
+    /// Not a tsc error. This is emitted as a note.
+    CastRemovesReadonly {
+        span: Span,
+    },
+
+    /// TS2578
+    UnusedTsExpectError {
+        span: Span,
+    },
+
     /// TS2367
     NoOverlap {
         span: Span,
@@ -773,6 +861,11 @@ pub enum Error {
         span: Span,
     },
 
+    /// TS7017
+    ImplicitAnyBecauseGlobalThisHasNoIndexSignature {
+        span: Span,
+    },
+
     /// TS7022
     ImplicitAnyBecauseOfSelfRef {
         span: Span,
@@ -836,6 +929,11 @@ pub enum Error {
         span: Span,
     },
 
+    /// TS2374
+    DuplicateIndexSignature {
+        span: Span,
+    },
+
     /// TS2695
     UselessSeqExpr {
         span: Span,
@@ -879,6 +977,21 @@ pub enum Error {
         span: Span,
     },
 
+    /// TS1257
+    RequiredTupleElementAfterOptional {
+        span: Span,
+    },
+
+    /// TS1265
+    RestTupleElementAfterRest {
+        span: Span,
+    },
+
+    /// TS1266
+    OptionalTupleElementAfterRest {
+        span: Span,
+    },
+
     /// TS2539
     NotVariable {
         // Span of rhs
@@ -1050,6 +1163,11 @@ pub enum Error {
         span: Span,
     },
 
+    /// TS1014
+    TS1014 {
+        span: Span,
+    },
+
     TS1016 {
         span: Span,
     },
@@ -1235,6 +1353,13 @@ pub enum Error {
         span: Span,
     },
 
+    /// TS2352
+    ///
+    /// Same as [Error::NonOverlappingTypeCast], but emitted as a warning.
+    NonOverlappingTypeCastWarning {
+        span: Span,
+    },
+
     InvalidOperatorForLhs {
         span: Span,
         op: AssignOp,
@@ -1316,6 +1441,11 @@ pub enum Error {
         span: Span,
     },
 
+    /// TS1245
+    AbstractMethodWithBody {
+        span: Span,
+    },
+
     /// TS2387
     ShouldBeStaticMethod {
         span: Span,
@@ -1545,6 +1675,7 @@ impl Error {
     /// TypeScript error code.
     pub fn code(&self) -> usize {
         match self {
+            Error::TS1014 { .. } => 1014,
             Error::TS1016 { .. } => 1016,
             Error::TS1063 { .. } => 1063,
             Error::TS1094 { .. } => 1094,
@@ -1589,10 +1720,14 @@ impl Error {
             | Error::InvalidOpAssign { .. }
             | Error::TupleAssignError { .. } => 2322,
 
-            Error::NonOverlappingTypeCast { .. } => 2352,
+            Error::NonOverlappingTypeCast { .. } | Error::NonOverlappingTypeCastWarning { .. } => 2352,
+
+            Error::UnusedTsExpectError { .. } => 2578,
 
             Error::SuperInClassWithoutSuper { .. } => 2335,
 
+            Error::SuperOutsideClassOrObjectLit { .. } => 2660,
+
             Error::NoSuchProperty { .. }
             | Error::NoSuchPropertyInThis { .. }
             | Error::NoSuchPropertyInClass { .. }
@@ -1607,6 +1742,15 @@ impl Error {
 
             Error::NoInitAndNoDefault { .. } => 2525,
 
+            Error::TypeParameterCountMismatch { .. } => 2558,
+            Error::GenericTypeArgCountMismatch { min, max, .. } => {
+                if min == max {
+                    2314
+                } else {
+                    2707
+                }
+            }
+
             Error::ExpectedNArgsButGotM { .. } => 2554,
             Error::ExpectedAtLeastNArgsButGotM { .. } => 2555,
             Error::ExpectedNArgsButGotMOrMore { .. } => 2556,
@@ -1618,6 +1762,8 @@ impl Error {
 
             Error::DuplicateName { .. } | Error::DuplicateNameWithoutName { .. } => 2300,
 
+            Error::DuplicateIndexSignature { .. } => 2374,
+
             Error::NoSuchVar { .. } => 2304,
             Error::NoSuchType { .. } => 2304,
             Error::NoSuchTypeButVarExists { .. } => 2749,
@@ -1625,7 +1771,7 @@ impl Error {
 
             Error::CannotAssignAbstractConstructorToNonAbstractConstructor { .. } => 2322,
             Error::CannotCreateInstanceOfAbstractClass { .. } => 2511,
-            Error::WrongArgType { .. } => 2345,
+            Error::WrongArgType { .. } | Error::WrongArgTypeOfLabeledElement { .. } => 2345,
 
             Error::ComputedMemberInEnumWithStrMember { .. } => 2553,
 
@@ -1662,6 +1808,8 @@ impl Error {
 
             Error::InvalidTupleCast { .. } => 2352,
 
+            Error::TupleCastWithExcessElements { .. } => 2352,
+
             Error::NoOverlap { .. } => 2367,
 
             Error::InvalidLhsInInstanceOf { .. } => 2358,
@@ -1718,6 +1866,8 @@ impl Error {
 
             Error::ClassIncorrectlyImplementsInterface { .. } => 2420,
 
+            Error::ClassImplementsNonObjectType { .. } => 2422,
+
             Error::ExportMixedWithLocal { .. } => 2395,
 
             Error::NotConstructorType { .. } => 2507,
@@ -1730,6 +1880,8 @@ impl Error {
 
             Error::DuplicateProperty { .. } => 2300,
 
+            Error::SubsequentPropertyDeclarationMustHaveSameType { .. } => 2717,
+
             Error::CannotCallWithNewNonVoidFunction { .. } => 2350,
 
             Error::InvalidInterfaceName { .. } => 2427,
@@ -1772,6 +1924,8 @@ impl Error {
 
             Error::ImplicitAnyBecauseIndexTypeIsWrong { .. } => 7053,
 
+            Error::ImplicitAnyBecauseGlobalThisHasNoIndexSignature { .. } => 7017,
+
             Error::ImplicitAnyBecauseOfSelfRef { .. } => 7022,
 
             Error::ConstructorIsKeyword { .. } => 18012,
@@ -1784,6 +1938,8 @@ impl Error {
 
             Error::OptionalChainCannotContainPrivateIdentifier { .. } => 18030,
 
+            Error::PrivateIdentifierOutsideClass { .. } => 18016,
+
             Error::TypeAnnOnLhsOfForInLoops { .. } => 2404,
             Error::TypeAnnOnLhsOfForOfLoops { .. } => 2483,
 
@@ -1853,6 +2009,16 @@ impl Error {
 
             Error::AbstractAndConcreteIsMixed { .. } => 2512,
 
+            Error::AbstractMethodWithBody { .. } => 1245,
+
+            Error::RequiredTupleElementAfterOptional { .. } => 1257,
+
+            Error::RestTupleElementAfterRest { .. } => 1265,
+
+            Error::OptionalTupleElementAfterRest { .. } => 1266,
+
+            Error::ExcessivelyDeepTypeInstantiation { .. } => 2589,
+
             Error::AbstractClassMethodShouldBeSequntial { .. } => 2516,
 
             Error::OperatorCannotBeAppliedToTypes { .. } => 2365,
@@ -1885,6 +2051,10 @@ impl Error {
 
             Error::DefinedWitHAccessorInSuper { .. } => 2610,
 
+            Error::GetterLessAccessibleThanSetter { .. } => 2808,
+
+            Error::PropertyIncompatibleWithSuperClassMember { .. } => 2416,
+
             Error::ClassPropNotInitialized { .. } => 2564,
 
             Error::VarMayNotBeInitialized { .. } => 2454,
@@ -1951,20 +2121,62 @@ impl Error {
         match self {
             Self::Unimplemented { msg, .. } => format!("unimplemented: {}", msg).into(),
 
+            Self::NumberBigIntCast { .. } => "numbers and bigints are not interchangeable".into(),
+
+            Self::CastRemovesReadonly { .. } => "the type cast removes `readonly` from the tuple".into(),
+
+            Self::TupleCastWithExcessElements {
+                source_len, target_len, ..
+            } => format!("source has {} elements but target tuple has {}", source_len, target_len).into(),
+
             _ => format!("{:#?}", self).into(),
         }
     }
 
+    /// Returns `true` if this is an informational diagnostic, not an error.
+    pub fn is_note(&self) -> bool {
+        matches!(
+            self.actual(),
+            Error::TupleLabelChangedByCast { .. } | Error::NumberBigIntCast { .. } | Error::CastRemovesReadonly { .. }
+        )
+    }
+
+    /// Returns `true` if this should be emitted as a warning, not an error.
+    pub fn is_warning(&self) -> bool {
+        matches!(self.actual(), Error::NonOverlappingTypeCastWarning { .. })
+    }
+
     #[cold]
     pub fn emit(self, h: &Handler) {
         let span = self.span();
 
+        if self.is_note() {
+            h.span_note_without_error(span, &self.msg());
+            return;
+        }
+
+        if self.is_warning() {
+            h.struct_span_warn_with_code(
+                span,
+                &self.msg(),
+                DiagnosticId::Error(format!("TS{}", Self::normalize_error_code(self.code()))),
+            )
+            .emit();
+            return;
+        }
+
         let mut err = h.struct_span_err_with_code(
             span,
             &self.msg(),
             DiagnosticId::Error(format!("TS{}", Self::normalize_error_code(self.code()))),
         );
 
+        if let Error::TupleCastWithExcessElements { excess, .. } = self.actual() {
+            for span in excess {
+                err.span_label(*span, "excess element");
+            }
+        }
+
         err.emit();
     }
 
@@ -336,7 +336,10 @@ impl Analyzer<'_, '_> {
         };
 
         // TypeScript functions are bivariant if strict_function_types is false.
-        if !self.env.rule().strict_function_types || opts.is_params_of_method_definition {
+        //
+        // Parameter count is not bivariant, so we don't use this shortcut if rhs
+        // requires more parameters than lhs can provide.
+        if (!self.env.rule().strict_function_types || opts.is_params_of_method_definition) && !requires_more_params(l_params, r_params) {
             if self
                 .assign_params(
                     data,
@@ -721,6 +724,52 @@ impl Analyzer<'_, '_> {
         Ok(())
     }
 
+    /// Validates `this` parameters.
+    ///
+    /// ```ts
+    /// declare function foo(cb: (this: void) => void): void;
+    ///
+    /// function a(this: Foo) {}
+    /// function b(this: void) {}
+    ///
+    /// foo(a) // error
+    /// foo(b) // ok
+    /// ```
+    ///
+    /// It's not checked if `r` does not require `this` or if `l` does not
+    /// have a `this` parameter.
+    fn assign_this_params(&mut self, data: &mut AssignData, opts: AssignOpts, l: &[FnParam], r: &[FnParam]) -> VResult<()> {
+        let span = opts.span;
+
+        let find_this = |params: &[FnParam]| {
+            params
+                .iter()
+                .find(|p| match p.pat {
+                    RPat::Ident(RBindingIdent {
+                        id: RIdent { sym: js_word!("this"), .. },
+                        ..
+                    }) => true,
+                    _ => false,
+                })
+                .map(|p| p.ty.clone())
+        };
+
+        let (l_this, r_this) = match (find_this(l), find_this(r)) {
+            (Some(l_this), Some(r_this)) => (l_this, r_this),
+            _ => return Ok(()),
+        };
+
+        if r_this.is_kwd(TsKeywordTypeKind::TsVoidKeyword) {
+            return Ok(());
+        }
+
+        if self.assign_with_opts(data, opts, &r_this, &l_this).is_ok() || self.assign_with_opts(data, opts, &l_this, &r_this).is_ok() {
+            return Ok(());
+        }
+
+        Err(Error::SimpleAssignFailed { span, cause: None }).context("the `this` types of each signature are incompatible")
+    }
+
     /// # Validation of parameter count
     ///
     /// A parameter named `this` is excluded.
@@ -741,25 +790,10 @@ impl Analyzer<'_, '_> {
     pub(crate) fn assign_params(&mut self, data: &mut AssignData, opts: AssignOpts, l: &[FnParam], r: &[FnParam]) -> VResult<()> {
         let span = opts.span;
 
-        let li = l.iter().filter(|p| match p.pat {
-            RPat::Ident(RBindingIdent {
-                id: RIdent { sym: js_word!("this"), .. },
-                ..
-            }) => false,
-            _ => true,
-        });
-        let ri = r.iter().filter(|p| match p.pat {
-            RPat::Ident(RBindingIdent {
-                id: RIdent { sym: js_word!("this"), .. },
-                ..
-            }) => false,
-            _ => true,
-        });
+        let li = l.iter().filter(|p| !is_this_param(p));
+        let ri = r.iter().filter(|p| !is_this_param(p));
 
-        let l_has_rest = l.iter().any(|p| match p.pat {
-            RPat::Rest(..) => true,
-            _ => false,
-        });
+        self.assign_this_params(data, opts, l, r)?;
 
         // TODO(kdy1): Consider optional parameters.
 
@@ -775,23 +809,20 @@ impl Analyzer<'_, '_> {
             }
         }
 
-        // Don't ask why.
-        if li.clone().count() < required_ri.clone().count() {
-            if !l_has_rest && required_non_void_li.clone().count() < required_non_void_ri.clone().count() {
-                // I don't know why, but overload signature does not need to match overloaded
-                // signature.
-                if opts.for_overload {
-                    return Ok(());
-                }
-
-                return Err(Error::SimpleAssignFailed { span, cause: None }).with_context(|| {
-                    format!(
-                        "!l_has_rest && l.params.required.len < r.params.required.len\nLeft: {:?}\nRight: {:?}\n",
-                        required_non_void_li.collect_vec(),
-                        required_non_void_ri.collect_vec()
-                    )
-                });
+        if requires_more_params(l, r) {
+            // I don't know why, but overload signature does not need to match overloaded
+            // signature.
+            if opts.for_overload {
+                return Ok(());
             }
+
+            return Err(Error::SimpleAssignFailed { span, cause: None }).with_context(|| {
+                format!(
+                    "!l_has_rest && l.params.required.len < r.params.required.len\nLeft: {:?}\nRight: {:?}\n",
+                    required_non_void_li.collect_vec(),
+                    required_non_void_ri.collect_vec()
+                )
+            });
         }
 
         for pair in li.zip_longest(ri) {
@@ -831,3 +862,39 @@ impl Analyzer<'_, '_> {
         Ok(())
     }
 }
+
+fn is_this_param(p: &FnParam) -> bool {
+    matches!(
+        p.pat,
+        RPat::Ident(RBindingIdent {
+            id: RIdent { sym: js_word!("this"), .. },
+            ..
+        })
+    )
+}
+
+/// Returns `true` if `r` requires more parameters than `l` can provide.
+///
+/// Optional parameters, rest parameters and parameters of type `void` are not
+/// required.
+///
+/// ```ts
+/// declare var a: (x: string) => any;
+/// declare var b: (x: string, y: number) => any;
+///
+/// a = b; // error
+/// ```
+fn requires_more_params(l: &[FnParam], r: &[FnParam]) -> bool {
+    let li = l.iter().filter(|p| !is_this_param(p));
+    let ri = r.iter().filter(|p| !is_this_param(p));
+
+    let l_has_rest = l.iter().any(|p| matches!(p.pat, RPat::Rest(..)));
+
+    let required_ri = ri.clone().filter(|i| i.required);
+
+    let required_non_void_li = li.clone().filter(|i| i.required && !i.ty.is_kwd(TsKeywordTypeKind::TsVoidKeyword));
+    let required_non_void_ri = ri.filter(|i| i.required && !i.ty.is_kwd(TsKeywordTypeKind::TsVoidKeyword));
+
+    // Don't ask why.
+    li.count() < required_ri.count() && !l_has_rest && required_non_void_li.count() < required_non_void_ri.count()
+}
@@ -152,9 +152,17 @@ pub(crate) struct AssignOpts {
     pub is_params_of_method_definition: bool,
 }
 
+/// Maximum depth of nested assignments before we give up and report
+/// [Error::ExcessivelyDeepTypeInstantiation].
+const MAX_ASSIGN_DEPTH: usize = 100;
+
 #[derive(Default)]
 pub struct AssignData {
     dejavu: Vec<(Type, Type)>,
+
+    /// `true` if [MAX_ASSIGN_DEPTH] is exceeded while checking the current
+    /// assignment, and no assignment containing it succeeded.
+    depth_limit_exceeded: bool,
 }
 
 impl Analyzer<'_, '_> {
@@ -390,10 +398,19 @@ impl Analyzer<'_, '_> {
 
         // self.verify_before_assign("lhs", left);
         // self.verify_before_assign("rhs", right);
+        let is_root = data.dejavu.is_empty();
         let res = self.assign_inner(data, left, right, opts);
 
+        let depth_limit_exceeded = data.depth_limit_exceeded;
+        if is_root {
+            data.depth_limit_exceeded = false;
+        }
+
         match res {
             Err(Error::Errors { errors, .. }) if errors.is_empty() => return Ok(()),
+            Err(..) if is_root && depth_limit_exceeded => {
+                return Err(Error::ExcessivelyDeepTypeInstantiation { span: opts.span });
+            }
             _ => {}
         }
 
@@ -507,6 +524,13 @@ impl Analyzer<'_, '_> {
         }
         let _stack = stack::track(opts.span)?;
 
+        // Mutually recursive generic types produce a new pair of types on each
+        // level, so `dejavu` alone cannot stop the recursion.
+        if data.dejavu.len() >= MAX_ASSIGN_DEPTH {
+            data.depth_limit_exceeded = true;
+            return Err(Error::ExcessivelyDeepTypeInstantiation { span: opts.span });
+        }
+
         data.dejavu.push((left.clone(), right.clone()));
 
         let res = self.assign_without_wrapping(data, left, right, opts).with_context(|| {
@@ -520,6 +544,12 @@ impl Analyzer<'_, '_> {
         let dejavu = data.dejavu.pop();
         debug_assert!(dejavu.is_some());
 
+        // The limit was exceeded only by attempts which failed without affecting
+        // the result, like other members of a union.
+        if res.is_ok() {
+            data.depth_limit_exceeded = false;
+        }
+
         debug!("[assign ({:?})] {} = {}\n{:?} ", res.is_ok(), l, r, opts);
 
         res
@@ -2003,6 +2033,39 @@ impl Analyzer<'_, '_> {
                 //
                 match *rhs.normalize() {
                     Type::Tuple(Tuple { elems: ref rhs_elems, .. }) => {
+                        // [number, ...number[]] accepts extra elements.
+                        if let Some((rest, fixed)) = elems.split_last() {
+                            if rest.ty.is_rest() && !rhs_elems.iter().any(|el| el.ty.is_rest()) {
+                                if rhs_elems.len() < fixed.len() {
+                                    return Err(Error::AssignFailedBecauseTupleLengthDiffers { span });
+                                }
+
+                                let mut errors = vec![];
+                                for (i, r) in rhs_elems.iter().enumerate() {
+                                    let l = fixed.get(i).unwrap_or(rest);
+
+                                    errors.extend(
+                                        self.assign_inner(
+                                            data,
+                                            &l.ty,
+                                            &r.ty,
+                                            AssignOpts {
+                                                allow_unknown_rhs: true,
+                                                ..opts
+                                            },
+                                        )
+                                        .err(),
+                                    );
+                                }
+
+                                if !errors.is_empty() {
+                                    return Err(Error::TupleAssignError { span, errors });
+                                }
+
+                                return Ok(());
+                            }
+                        }
+
                         // TODO: Handle Type::Rest
 
                         if elems.len() < rhs_elems.len() {
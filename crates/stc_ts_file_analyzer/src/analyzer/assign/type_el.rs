@@ -1188,7 +1188,7 @@ impl Analyzer<'_, '_> {
                                 TypeElement::Index(ri) => {
                                     done = true;
 
-                                    if li.params.type_eq(&ri.params) || ri.params[0].ty.is_kwd(TsKeywordTypeKind::TsStringKeyword) {
+                                    if is_index_key_applicable(&li.params[0].ty, &ri.params[0].ty) {
                                         if let Some(pos) = unhandled_rhs.iter().position(|span| *span == ri.span()) {
                                             unhandled_rhs.remove(pos);
                                         }
@@ -1343,3 +1343,20 @@ impl Analyzer<'_, '_> {
         Ok(())
     }
 }
+
+/// Returns `true` if the value type of an index signature with the key type
+/// `r_key` should be compared with the value type of an index signature with
+/// the key type `l_key`.
+///
+/// ```ts
+/// declare let a: { [k: string]: number };
+/// declare let b: { [k: number]: 1 };
+///
+/// a = b; // Ok, values of `b` are compared with `number`.
+/// b = a; // Error, `number` is not assignable to `1`.
+/// ```
+fn is_index_key_applicable(l_key: &Type, r_key: &Type) -> bool {
+    l_key.type_eq(r_key)
+        || r_key.is_kwd(TsKeywordTypeKind::TsStringKeyword)
+        || (l_key.is_kwd(TsKeywordTypeKind::TsStringKeyword) && r_key.is_kwd(TsKeywordTypeKind::TsNumberKeyword))
+}
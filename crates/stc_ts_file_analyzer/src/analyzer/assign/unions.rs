@@ -5,7 +5,8 @@ use stc_ts_ast_rnode::RBool;
 use stc_ts_errors::{DebugExt, Error};
 use stc_ts_type_ops::Fix;
 use stc_ts_types::{
-    KeywordType, LitType, LitTypeMetadata, PropertySignature, Tuple, TupleElement, Type, TypeElement, TypeLit, Union, UnionMetadata,
+    KeywordType, LitType, LitTypeMetadata, PropertySignature, RestType, Tuple, TupleElement, Type, TypeElement, TypeLit, Union,
+    UnionMetadata,
 };
 use stc_utils::cache::{Freeze, ALLOW_DEEP_CLONE};
 use swc_common::{Span, DUMMY_SP};
@@ -133,7 +134,12 @@ impl Analyzer<'_, '_> {
 
     /// TODO(kdy1): Use Cow<TupleElement>
     fn append_tuple_element_to_type(&mut self, span: Span, to: &mut Type, el: &TupleElement) -> VResult<()> {
-        if let Some(el_ty) = self.expand_union_for_assignment(span, &el.ty) {
+        let el_ty = match el.ty.normalize() {
+            Type::Rest(rest) => self.expand_rest_for_assignment(span, rest),
+            _ => self.expand_union_for_assignment(span, &el.ty),
+        };
+
+        if let Some(el_ty) = el_ty {
             let mut to_types = (0..el_ty.types.len()).map(|_| to.clone()).collect_vec();
 
             for (idx, el_ty) in el_ty.types.iter().enumerate() {
@@ -174,6 +180,32 @@ impl Analyzer<'_, '_> {
         }
     }
 
+    /// Expands `...(A[] | B[])` to `...A[] | ...B[]`.
+    ///
+    /// `...(A | B)[]` is not expanded because `[...(A | B)[]]` is not same as
+    /// `[...A[]] | [...B[]]`.
+    fn expand_rest_for_assignment(&mut self, span: Span, rest: &RestType) -> Option<Union> {
+        let ty = self.normalize(Some(span), Cow::Borrowed(&rest.ty), Default::default()).ok()?;
+
+        match ty.normalize() {
+            Type::Union(ty) => Some(Union {
+                types: ty
+                    .types
+                    .iter()
+                    .map(|ty| {
+                        Type::Rest(RestType {
+                            span: rest.span,
+                            ty: box ty.clone(),
+                            metadata: rest.metadata,
+                        })
+                    })
+                    .collect(),
+                ..ty.clone()
+            }),
+            _ => None,
+        }
+    }
+
     /// Expands `boolean` to `true | false`.
     fn expand_union_for_assignment<'a>(&mut self, span: Span, t: &'a Type) -> Option<Union> {
         let t = self.normalize(Some(span), Cow::Borrowed(t), Default::default()).ok()?;
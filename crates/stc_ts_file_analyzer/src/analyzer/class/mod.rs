@@ -17,11 +17,11 @@ use stc_ts_file_analyzer_macros::extra_validator;
 use stc_ts_simple_ast_validations::consturctor::ConstructorSuperCallFinder;
 use stc_ts_type_ops::generalization::{prevent_generalize, LitGeneralizer};
 use stc_ts_types::{
-    Accessor, Class, ClassDef, ClassMember, ClassMetadata, ClassProperty, ComputedKey, ConstructorSignature, FnParam, Id, Intersection,
-    Key, KeywordType, Method, Operator, OperatorMetadata, QueryExpr, QueryType, QueryTypeMetadata, Ref, TsExpr, Type,
+    Accessor, Class, ClassDef, ClassMember, ClassMetadata, ClassProperty, ComputedKey, ConstructorSignature, FnParam, Function, Id,
+    Intersection, Key, KeywordType, Method, Operator, OperatorMetadata, QueryExpr, QueryType, QueryTypeMetadata, Ref, TsExpr, Type,
 };
 use stc_utils::{cache::Freeze, AHashSet};
-use swc_atoms::js_word;
+use swc_atoms::{js_word, JsWord};
 use swc_common::{iter::IdentifyLast, EqIgnoreSpan, Span, Spanned, SyntaxContext, TypeEq, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_utils::private_ident;
@@ -51,6 +51,9 @@ pub(crate) struct ClassState {
     ///
     /// `false` means `this` can be used.
     pub need_super_call: RefCell<bool>,
+
+    /// Private names (`#foo`) declared by the class.
+    pub private_names: Vec<JsWord>,
 }
 
 impl Analyzer<'_, '_> {
@@ -509,6 +512,7 @@ impl Analyzer<'_, '_> {
                 }
 
                 let params = c.function.params.validate_with(child)?;
+                child.report_error_for_misplaced_rest_params(&params);
 
                 let declared_ret_ty = try_opt!(c.function.return_type.validate_with(child));
 
@@ -612,7 +616,15 @@ impl Analyzer<'_, '_> {
                     // It's error if abstract method has a body
 
                     if c.is_abstract && c.function.body.is_some() {
-                        child.storage.report(Error::TS1318 { span: key_span });
+                        match c.kind {
+                            MethodKind::Method => {
+                                child.storage.report(Error::AbstractMethodWithBody { span: key_span });
+                            }
+                            // An abstract accessor cannot have an implementation.
+                            MethodKind::Getter | MethodKind::Setter => {
+                                child.storage.report(Error::TS1318 { span: key_span });
+                            }
+                        }
                     }
                 }
 
@@ -654,6 +666,7 @@ impl Analyzer<'_, '_> {
                 }
 
                 let params = c.function.params.validate_with(child)?;
+                child.report_error_for_misplaced_rest_params(&params);
 
                 // c.function.visit_children_with(child);
 
@@ -919,6 +932,48 @@ impl Analyzer<'_, '_> {
         Ok(())
     }
 
+    /// A getter should be at least as accessible as the setter of the same
+    /// property.
+    ///
+    /// ```ts
+    /// class C {
+    ///     protected get a() { return 0; }
+    ///     set a(v) {} // error
+    /// }
+    /// ```
+    fn report_errors_for_accessors_with_different_accessibility(&mut self, c: &RClass) {
+        fn rank(accessibility: Option<Accessibility>) -> u8 {
+            match accessibility {
+                None | Some(Accessibility::Public) => 0,
+                Some(Accessibility::Protected) => 1,
+                Some(Accessibility::Private) => 2,
+            }
+        }
+
+        let accessors = c
+            .body
+            .iter()
+            .filter_map(|member| match member {
+                RClassMember::Method(m) if m.kind == MethodKind::Getter || m.kind == MethodKind::Setter => Some(m),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        for getter in accessors.iter().filter(|m| m.kind == MethodKind::Getter) {
+            for setter in accessors.iter().filter(|m| m.kind == MethodKind::Setter) {
+                if getter.is_static != setter.is_static || !is_prop_name_eq(&getter.key, &setter.key) {
+                    continue;
+                }
+
+                if rank(getter.accessibility) > rank(setter.accessibility) {
+                    for span in [getter.key.span(), setter.key.span()] {
+                        self.storage.report(Error::GetterLessAccessibleThanSetter { span });
+                    }
+                }
+            }
+        }
+    }
+
     fn report_errors_for_statics_mixed_with_instances(&mut self, c: &RClass) -> VResult<()> {
         if self.ctx.in_declare {
             return Ok(());
@@ -1325,7 +1380,12 @@ impl Analyzer<'_, '_> {
 
         for parent in &*class.implements {
             let res: VResult<_> = try {
-                let parent = self.type_of_ts_entity_name(parent.span(), self.ctx.module_id, &parent.expr, parent.type_args.as_deref())?;
+                let span = parent.span();
+                let parent = self.type_of_ts_entity_name(span, self.ctx.module_id, &parent.expr, parent.type_args.as_deref())?;
+
+                if !self.is_valid_implements_target(span, &parent) {
+                    Err(Error::ClassImplementsNonObjectType { span })?
+                }
 
                 self.assign_with_opts(
                     &mut Default::default(),
@@ -1374,6 +1434,30 @@ impl Analyzer<'_, '_> {
         }
     }
 
+    /// Returns `false` if `ty` cannot be implemented by a class, because it's
+    /// not an object type or an intersection of object types.
+    ///
+    /// ```ts
+    /// type Str = string;
+    /// class C implements Str {}
+    /// ```
+    fn is_valid_implements_target(&mut self, span: Span, ty: &Type) -> bool {
+        let ty = match self.normalize(Some(span), Cow::Borrowed(ty), Default::default()) {
+            Ok(ty) => ty,
+            Err(..) => return true,
+        };
+
+        match ty.normalize() {
+            Type::Keyword(KeywordType {
+                kind: TsKeywordTypeKind::TsAnyKeyword,
+                ..
+            }) => true,
+            Type::Keyword(..) | Type::Lit(..) | Type::Union(..) | Type::Enum(..) | Type::EnumVariant(..) => false,
+            Type::Intersection(i) => i.types.iter().all(|ty| self.is_valid_implements_target(span, ty)),
+            _ => true,
+        }
+    }
+
     /// Should be called only from `Validate<Class>`.
     fn validate_inherited_members_from_super_class(&mut self, name: Option<Span>, class: &ClassDef) {
         if class.is_abstract || self.ctx.in_declare {
@@ -1392,6 +1476,77 @@ impl Analyzer<'_, '_> {
         }
     }
 
+    /// A property can override a method of the super class, but the type of the
+    /// property should be assignable to the method.
+    ///
+    /// This is checked only if `useDefineForClassFields` is enabled.
+    ///
+    /// ```ts
+    /// class A {
+    ///     m() {}
+    /// }
+    /// class B extends A {
+    ///     m = 1; // error
+    /// }
+    /// ```
+    fn report_errors_for_properties_shadowing_super_methods(&mut self, class: &ClassDef) {
+        if !self.rule().use_define_property_for_class_fields {
+            return;
+        }
+
+        let mut super_classes = vec![];
+        let mut super_ty = class.super_class.clone();
+        while let Some(ty) = super_ty.take() {
+            let super_class = match self.normalize(Some(class.span), Cow::Owned(*ty), Default::default()) {
+                Ok(ty) => match ty.normalize() {
+                    Type::ClassDef(sc) => sc.clone(),
+                    _ => break,
+                },
+                Err(..) => break,
+            };
+
+            super_ty = super_class.super_class.clone();
+            super_classes.push(super_class);
+        }
+
+        for member in &class.body {
+            let p = match member {
+                ClassMember::Property(p) if !p.is_static && !p.accessor.getter && !p.accessor.setter => p,
+                _ => continue,
+            };
+            let value = match &p.value {
+                Some(v) => v,
+                None => continue,
+            };
+
+            // Only the nearest declaration of the key is checked.
+            let super_member = super_classes.iter().find_map(|super_class| {
+                super_class.body.iter().find(|m| match m {
+                    ClassMember::Method(m) => !m.is_static && m.key.type_eq(&p.key),
+                    ClassMember::Property(sp) => !sp.is_static && sp.key.type_eq(&p.key),
+                    _ => false,
+                })
+            });
+            let super_method = match super_member {
+                Some(ClassMember::Method(m)) if m.accessibility != Some(Accessibility::Private) => m,
+                _ => continue,
+            };
+
+            let method_ty = Type::Function(Function {
+                span: super_method.span,
+                type_params: super_method.type_params.clone(),
+                params: super_method.params.clone(),
+                ret_ty: super_method.ret_ty.clone(),
+                metadata: Default::default(),
+            });
+
+            if self.assign(p.span, &mut Default::default(), &method_ty, value).is_err() {
+                self.storage
+                    .report(Error::PropertyIncompatibleWithSuperClassMember { span: p.key.span() });
+            }
+        }
+    }
+
     fn report_error_for_wrong_super_class_inheritance(&mut self, span: Span, members: &[ClassMember], super_ty: &Type) {
         let super_ty = self.normalize(Some(span), Cow::Borrowed(super_ty), Default::default());
         let super_ty = match super_ty {
@@ -1552,6 +1707,16 @@ impl Analyzer<'_, '_> {
 
             // Register the class.
             child.scope.this_class_name = name.clone();
+            child.scope.class.private_names = c
+                .body
+                .iter()
+                .filter_map(|member| match member {
+                    RClassMember::PrivateProp(RPrivateProp { key, .. }) | RClassMember::PrivateMethod(RPrivateMethod { key, .. }) => {
+                        Some(key.id.sym.clone())
+                    }
+                    _ => None,
+                })
+                .collect();
 
             // We handle type parameters first.
             let type_params = try_opt!(c.type_params.validate_with(child)).map(Box::new);
@@ -1695,6 +1860,7 @@ impl Analyzer<'_, '_> {
                 .report_errors_for_wrong_ambient_methods_of_class(c, false)
                 .report(&mut child.storage);
             child.report_errors_for_statics_mixed_with_instances(&c).report(&mut child.storage);
+            child.report_errors_for_accessors_with_different_accessibility(&c);
             child.report_errors_for_duplicate_class_members(&c).report(&mut child.storage);
 
             child.scope.super_class = super_class.clone().map(|ty| make_instance_type(child.ctx.module_id, *ty));
@@ -1995,6 +2161,7 @@ impl Analyzer<'_, '_> {
                 .report(&mut child.storage);
 
             child.validate_inherited_members_from_super_class(None, &class);
+            child.report_errors_for_properties_shadowing_super_methods(&class);
             child.report_errors_for_wrong_impls_of_class(None, &class);
             child.report_errors_for_confliicting_interfaces(&class.implements);
 
@@ -7,9 +7,10 @@ use std::{
 };
 
 use fxhash::FxHashMap;
-use rnode::{NodeId, VisitWith};
+use rnode::{NodeId, Visit, VisitWith};
 use stc_ts_ast_rnode::{
-    RBinExpr, RBindingIdent, RCondExpr, RExpr, RIdent, RIfStmt, RObjectPatProp, RPat, RPatOrExpr, RStmt, RSwitchCase, RSwitchStmt,
+    RBinExpr, RBindingIdent, RCondExpr, RExpr, RExprOrSuper, RIdent, RIfStmt, RObjectPatProp, RPat, RPatOrExpr, RStmt, RSwitchCase,
+    RSwitchStmt, RVarDeclarator,
 };
 use stc_ts_errors::{debug::dump_type_as_string, DebugExt, Error};
 use stc_ts_type_ops::Fix;
@@ -29,7 +30,7 @@ use crate::{
     analyzer::{
         assign::AssignOpts,
         expr::{AccessPropertyOpts, IdCtx, TypeOfMode},
-        scope::{ScopeKind, VarInfo},
+        scope::{ScopeKind, VarInfo, VarKind},
         util::ResultExt,
         Analyzer, Ctx,
     },
@@ -382,6 +383,10 @@ impl Analyzer<'_, '_> {
 
         self.cur_facts = prev_facts.clone();
         self.with_child(ScopeKind::Flow, true_facts, |child: &mut Analyzer| {
+            if child.rule().report_never_narrowed_blocks {
+                child.report_error_for_never_narrowed_block(&stmt.cons);
+            }
+
             stmt.cons.visit_with(child);
 
             cons_ends_with_unreachable = child.ctx.in_unreachable;
@@ -424,6 +429,41 @@ impl Analyzer<'_, '_> {
 }
 
 impl Analyzer<'_, '_> {
+    /// Reports `body` as unreachable if the condition guarding it narrows a
+    /// variable to `never`, like `typeof x === "string" && typeof x ===
+    /// "number"`.
+    ///
+    /// Exhaustiveness checks like `assertNever(x)` are not reported because
+    /// they use the narrowed variable.
+    fn report_error_for_never_narrowed_block(&mut self, body: &RStmt) {
+        let names = self
+            .scope
+            .facts
+            .facts
+            .keys()
+            .filter(|name| name.len() == 1)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for name in names {
+            let id = name.top();
+            let ty = match self.find_var_type(&id, TypeOfMode::RValue) {
+                Some(ty) => ty.into_owned(),
+                None => continue,
+            };
+            if ty.is_never() || !self.apply_type_facts(&name, ty).is_never() {
+                continue;
+            }
+
+            let mut finder = IdentUsageFinder { id: &id, found: false };
+            body.visit_with(&mut finder);
+            if !finder.found {
+                self.storage.report(Error::UnreachableCode { span: body.span() });
+                return;
+            }
+        }
+    }
+
     /// This method may remove `SafeSubscriber` from `Subscriber` |
     /// `SafeSubscriber` or downgrade the type, like converting `Subscriber` |
     /// `SafeSubscriber` into `SafeSubscriber`. This behavior is controlled by
@@ -541,6 +581,43 @@ impl Analyzer<'_, '_> {
         Ok(new)
     }
 
+    /// Remembers `obj.kind` for `const k = obj.kind`, so that `switch (k)` can
+    /// narrow `obj`.
+    ///
+    /// Aliases declared with `let` are ignored because they can be reassigned.
+    pub(super) fn store_aliased_discriminant(&mut self, kind: VarDeclKind, v: &RVarDeclarator) {
+        if kind != VarDeclKind::Const {
+            return;
+        }
+
+        let (alias, member) = match (&v.name, &v.init) {
+            (RPat::Ident(alias), Some(box RExpr::Member(member))) => (alias, member),
+            _ => return,
+        };
+        let (obj, prop) = match (&member.obj, &*member.prop, member.computed) {
+            (RExprOrSuper::Expr(box RExpr::Ident(obj)), RExpr::Ident(prop), false) => (obj, prop),
+            _ => return,
+        };
+
+        // Narrowing the object is not sound if it can be reassigned.
+        //
+        // TODO(kdy1): Allow parameters which are never reassigned.
+        let is_const = matches!(
+            self.scope.get_var(&obj.into()),
+            Some(VarInfo {
+                kind: VarKind::Var(VarDeclKind::Const),
+                ..
+            })
+        );
+        if !is_const {
+            return;
+        }
+
+        let mut name = Name::from(Id::from(obj));
+        name.push(prop.sym.clone());
+        self.data.aliased_discriminants.insert(alias.id.clone().into(), name);
+    }
+
     /// Returns the type of discriminant.
     ///
     /// TODO(kdy1): Implement this.
@@ -574,6 +651,19 @@ impl Analyzer<'_, '_> {
         let len = stmt.cases.len();
         let stmt_span = stmt.span();
 
+        // `const k = obj.kind; switch (k) {}` narrows `obj` too.
+        let aliased_discriminant = match &*stmt.discriminant {
+            RExpr::Ident(i) => {
+                let id = Id::from(i);
+                self.data
+                    .aliased_discriminants
+                    .get(&id)
+                    .cloned()
+                    .map(|discriminant| (Name::from(id), discriminant))
+            }
+            _ => None,
+        };
+
         let mut errored = false;
         // Check cases *in order*
         for (i, case) in stmt.cases.iter().enumerate() {
@@ -612,6 +702,15 @@ impl Analyzer<'_, '_> {
                             continue;
                         }
                     }
+
+                    // The alias is narrowed by the comparison above, and it equals to the
+                    // property of the object.
+                    if let Some((alias, discriminant)) = &aliased_discriminant {
+                        if let Some(case_ty) = a.cur_facts.true_facts.vars.get(alias).cloned() {
+                            a.add_type_facts_for_strict_equality(span, discriminant.clone(), &case_ty)
+                                .report(&mut a.storage);
+                        }
+                    }
                 }
                 None => {}
             }
@@ -1217,6 +1316,50 @@ impl Analyzer<'_, '_> {
         Ok(src.clone())
     }
 
+    /// Removes classes which do not declare `#name` from `src`.
+    ///
+    /// This is used for `#name in obj`.
+    pub(super) fn filter_types_with_private_name(&mut self, src: &Type, name: &JsWord) -> VResult<Type> {
+        src.assert_valid();
+
+        match src.normalize() {
+            Type::Ref(..) => {
+                let src = self.expand_top_ref(src.span(), Cow::Borrowed(src), Default::default())?;
+                return self.filter_types_with_private_name(&src, name);
+            }
+            Type::Union(ty) => {
+                let mut new_types = vec![];
+                for ty in &ty.types {
+                    let ty = self.filter_types_with_private_name(&ty, name)?;
+                    new_types.push(ty);
+                }
+                new_types.retain(|ty| !ty.is_never());
+                new_types.dedup_type();
+
+                return Ok(Type::new_union(ty.span, new_types));
+            }
+            Type::Class(c) => {
+                let declares_name = c.def.body.iter().any(|member| match member.key().as_deref() {
+                    Some(Key::Private(p)) => *p.id.sym() == *name,
+                    _ => false,
+                });
+
+                if !declares_name {
+                    return Ok(Type::never(
+                        src.span(),
+                        KeywordTypeMetadata {
+                            common: src.metadata(),
+                            ..Default::default()
+                        },
+                    ));
+                }
+            }
+            _ => {}
+        }
+
+        Ok(src.clone())
+    }
+
     fn determine_type_fact_by_field_fact(&mut self, span: Span, name: &Name, ty: &Type) -> VResult<Option<(Name, Type)>> {
         ty.assert_valid();
 
@@ -1342,3 +1485,17 @@ impl Facts {
         }
     }
 }
+
+/// Finds usages of a variable.
+struct IdentUsageFinder<'a> {
+    id: &'a Id,
+    found: bool,
+}
+
+impl Visit<RIdent> for IdentUsageFinder<'_> {
+    fn visit(&mut self, i: &RIdent) {
+        if *self.id == Id::from(i) {
+            self.found = true;
+        }
+    }
+}
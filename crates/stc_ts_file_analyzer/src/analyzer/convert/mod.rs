@@ -15,7 +15,7 @@ use stc_ts_errors::Error;
 use stc_ts_file_analyzer_macros::extra_validator;
 use stc_ts_type_ops::Fix;
 use stc_ts_types::{
-    type_id::SymbolId, Accessor, Alias, AliasMetadata, Array, CallSignature, CommonTypeMetadata, ComputedKey, Conditional,
+    type_id::SymbolId, Accessor, Alias, AliasMetadata, Array, CallSignature, ClassDef, CommonTypeMetadata, ComputedKey, Conditional,
     ConstructorSignature, FnParam, Id, IdCtx, ImportType, IndexSignature, IndexedAccessType, InferType, InferTypeMetadata, Interface,
     Intersection, Intrinsic, IntrinsicKind, Key, KeywordType, KeywordTypeMetadata, LitType, LitTypeMetadata, Mapped, MethodSignature,
     Operator, OptionalType, Predicate, PropertySignature, QueryExpr, QueryType, Ref, RefMetadata, RestType, Symbol, ThisType, TplType,
@@ -25,7 +25,7 @@ use stc_ts_types::{
 use stc_ts_utils::{find_ids_in_pat, PatExt};
 use stc_utils::{cache::Freeze, debug_ctx, ext::TypeVecExt, AHashSet};
 use swc_atoms::js_word;
-use swc_common::{Spanned, SyntaxContext, TypeEq, DUMMY_SP};
+use swc_common::{Span, Spanned, SyntaxContext, TypeEq, DUMMY_SP};
 use swc_ecma_ast::TsKeywordTypeKind;
 use tracing::warn;
 
@@ -357,10 +357,14 @@ impl Analyzer<'_, '_> {
 impl Analyzer<'_, '_> {
     fn validate(&mut self, d: &RTsConstructSignatureDecl) -> VResult<ConstructorSignature> {
         let type_params = try_opt!(d.type_params.validate_with(self));
+        let params: Vec<FnParam> = d.params.validate_with(self)?;
+        self.report_error_for_misplaced_rest_params(&params);
+        self.report_error_for_required_params_after_optional(&params);
+
         Ok(ConstructorSignature {
             accessibility: None,
             span: d.span,
-            params: d.params.validate_with(self)?,
+            params,
             type_params,
             ret_ty: try_opt!(d.type_ann.validate_with(self)).map(Box::new),
         })
@@ -375,6 +379,8 @@ impl Analyzer<'_, '_> {
         let ret_ty = try_opt!(d.type_ann.validate_with(self)).map(Box::new);
 
         self.report_error_for_duplicate_params(&params);
+        self.report_error_for_misplaced_rest_params(&params);
+        self.report_error_for_required_params_after_optional(&params);
 
         Ok(CallSignature {
             span: d.span,
@@ -399,6 +405,8 @@ impl Analyzer<'_, '_> {
 
             let params = d.params.validate_with(child)?;
             child.report_error_for_duplicate_params(&params);
+            child.report_error_for_misplaced_rest_params(&params);
+            child.report_error_for_required_params_after_optional(&params);
 
             Ok(MethodSignature {
                 accessibility: None,
@@ -526,6 +534,8 @@ impl Analyzer<'_, '_> {
 
         let span = t.span;
 
+        self.validate_tuple_element_order(t);
+
         Ok(Tuple {
             span,
             elems: t.elem_types.validate_with(self)?,
@@ -540,6 +550,42 @@ impl Analyzer<'_, '_> {
     }
 }
 
+impl Analyzer<'_, '_> {
+    /// Elements of a tuple should be ordered as required, optional and rest.
+    ///
+    /// A required element may follow a rest element, and a rest element with a
+    /// generic type (e.g. `...T`) may follow another rest element.
+    fn validate_tuple_element_order(&mut self, t: &RTsTupleType) {
+        let mut seen_optional = false;
+        let mut seen_rest = false;
+        let mut seen_array_rest = false;
+
+        for elem in &t.elem_types {
+            match &*elem.ty {
+                RTsType::TsOptionalType(..) => {
+                    if seen_rest {
+                        self.storage.report(Error::OptionalTupleElementAfterRest { span: elem.span });
+                    }
+                    seen_optional = true;
+                }
+                RTsType::TsRestType(RTsRestType { type_ann, .. }) => {
+                    let is_array = matches!(&**type_ann, RTsType::TsArrayType(..));
+                    if is_array && seen_array_rest {
+                        self.storage.report(Error::RestTupleElementAfterRest { span: elem.span });
+                    }
+                    seen_rest = true;
+                    seen_array_rest |= is_array;
+                }
+                _ => {
+                    if seen_optional {
+                        self.storage.report(Error::RequiredTupleElementAfterOptional { span: elem.span });
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[validator]
 impl Analyzer<'_, '_> {
     fn validate(&mut self, node: &RTsTupleElement) -> VResult<TupleElement> {
@@ -653,6 +699,7 @@ impl Analyzer<'_, '_> {
 
             let mut params: Vec<_> = t.params.validate_with(child)?;
             params.make_clone_cheap();
+            child.report_error_for_misplaced_rest_params(&params);
 
             let mut ret_ty = box t.type_ann.validate_with(child)?;
 
@@ -731,6 +778,7 @@ impl Analyzer<'_, '_> {
 
                 if let Some(types) = self.find_type(self.ctx.module_id, &i.into())? {
                     let mut found = false;
+                    let mut type_params = None;
                     for ty in types {
                         found = true;
 
@@ -740,10 +788,34 @@ impl Analyzer<'_, '_> {
                         // We use type param instead of reference type if possible.
                         match ty.normalize() {
                             Type::Param(..) => return Ok(ty.into_owned()),
+                            Type::Interface(Interface {
+                                type_params: Some(decl), ..
+                            })
+                            | Type::Alias(Alias {
+                                type_params: Some(decl), ..
+                            })
+                            | Type::ClassDef(ClassDef {
+                                type_params: Some(decl), ..
+                            }) => {
+                                type_params.get_or_insert_with(|| decl.clone());
+                            }
                             _ => {}
                         }
                     }
 
+                    if let (Some(type_params), Some(type_args)) = (&type_params, &type_args) {
+                        if !self.is_builtin && type_params.params.len() < type_args.params.len() {
+                            // Type parameters with defaults can be omitted.
+                            let min = type_params.params.iter().filter(|param| param.default.is_none()).count();
+                            self.storage.report(Error::GenericTypeArgCountMismatch {
+                                span,
+                                min,
+                                max: type_params.params.len(),
+                                actual: type_args.params.len(),
+                            });
+                        }
+                    }
+
                     if !self.is_builtin && !found && self.ctx.in_actual_type {
                         if let Some(..) = self.scope.get_var(&i.into()) {
                             self.storage.report(Error::NoSuchTypeButVarExists { span, name: i.into() });
@@ -1054,9 +1126,27 @@ impl Analyzer<'_, '_> {
         }
 
         let mut prev_keys: Vec<Cow<_>> = vec![];
+        // Types of the parameters of index signatures, and whether they are reported.
+        let mut prev_index_keys: Vec<(&Type, Span, bool)> = vec![];
 
         for elem in elems {
             match elem {
+                // `[key: string]: number` and `[key: number]: number` are allowed together.
+                TypeElement::Index(IndexSignature { span, params, .. }) if params.len() == 1 => {
+                    let key_ty = &*params[0].ty;
+
+                    match prev_index_keys.iter_mut().find(|(prev, ..)| prev.type_eq(key_ty)) {
+                        Some((_, prev_span, reported)) => {
+                            if !*reported {
+                                self.storage.report(Error::DuplicateIndexSignature { span: *prev_span });
+                                *reported = true;
+                            }
+                            self.storage.report(Error::DuplicateIndexSignature { span: *span });
+                        }
+                        None => prev_index_keys.push((key_ty, *span, false)),
+                    }
+                }
+
                 // TODO(kdy1): Handle getter / setter
                 TypeElement::Property(PropertySignature {
                     accessor:
@@ -1114,6 +1204,42 @@ impl Analyzer<'_, '_> {
         }
     }
 
+    /// A rest parameter must be the last parameter of a function.
+    #[cfg_attr(debug_assertions, tracing::instrument(skip_all))]
+    pub(crate) fn report_error_for_misplaced_rest_params(&mut self, params: &[FnParam]) {
+        if self.is_builtin {
+            return;
+        }
+
+        if let Some((_, init)) = params.split_last() {
+            for param in init {
+                if let RPat::Rest(..) = param.pat {
+                    self.storage.report(Error::TS1014 { span: param.span });
+                }
+            }
+        }
+    }
+
+    /// A required parameter cannot follow an optional parameter. Parameters
+    /// with an initializer are optional.
+    #[cfg_attr(debug_assertions, tracing::instrument(skip_all))]
+    pub(crate) fn report_error_for_required_params_after_optional(&mut self, params: &[FnParam]) {
+        if self.is_builtin {
+            return;
+        }
+
+        let mut has_optional = false;
+        for param in params {
+            if param.required {
+                if has_optional {
+                    self.storage.report(Error::TS1016 { span: param.span });
+                }
+            } else {
+                has_optional = true;
+            }
+        }
+    }
+
     #[extra_validator]
     #[cfg_attr(debug_assertions, tracing::instrument(skip_all))]
     fn report_error_for_type_param_usages_in_static_members(&mut self, i: &RIdent) {
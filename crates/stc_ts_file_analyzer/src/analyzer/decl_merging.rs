@@ -1,10 +1,10 @@
 use std::borrow::Cow;
 
 use fxhash::FxHashMap;
-use stc_ts_errors::{debug::dump_type_as_string, DebugExt};
-use stc_ts_types::{ClassDef, ClassMember, ClassProperty, Id, Interface, Method, Type, TypeElement, TypeParam};
+use stc_ts_errors::{debug::dump_type_as_string, DebugExt, Error};
+use stc_ts_types::{ClassDef, ClassMember, ClassProperty, Id, Interface, Method, PropertySignature, Type, TypeElement, TypeParam};
 use stc_utils::cache::Freeze;
-use swc_common::{Span, Spanned};
+use swc_common::{Span, Spanned, TypeEq};
 use tracing::info;
 
 use crate::{analyzer::Analyzer, VResult};
@@ -41,6 +41,47 @@ impl Analyzer<'_, '_> {
         }
     }
 
+    /// Reports an error if a property declared by an interface merged into a
+    /// class has a type different from the property of the class.
+    ///
+    /// ```ts
+    /// class Foo {
+    ///     a: string;
+    /// }
+    ///
+    /// interface Foo {
+    ///     a: number; // Error
+    /// }
+    /// ```
+    fn report_error_for_conflicting_merged_property(&mut self, span: Span, class_members: &[ClassMember], p: &PropertySignature) {
+        let p_ty = match &p.type_ann {
+            Some(ty) => ty,
+            None => return,
+        };
+
+        for member in class_members {
+            let class_prop = match member {
+                ClassMember::Property(class_prop) if !class_prop.is_static && class_prop.key.type_eq(&p.key) => class_prop,
+                _ => continue,
+            };
+            let class_prop_ty = match &class_prop.value {
+                Some(ty) => ty,
+                None => continue,
+            };
+
+            if class_prop_ty.type_eq(p_ty)
+                || (self.assign(span, &mut Default::default(), class_prop_ty, p_ty).is_ok()
+                    && self.assign(span, &mut Default::default(), p_ty, class_prop_ty).is_ok())
+            {
+                continue;
+            }
+
+            // The error is reported at the declaration which comes later.
+            let span = if class_prop.span.lo > p.span.lo { class_prop.span } else { p.span };
+            self.storage.report(Error::SubsequentPropertyDeclarationMustHaveSameType { span });
+        }
+    }
+
     /// Handle declaration merging. This method is used to avoid implementing
     /// same logic twice.
     fn merge_from_to(&mut self, span: Span, a: Type, b: Type) -> VResult<Option<Type>> {
@@ -82,6 +123,10 @@ impl Analyzer<'_, '_> {
                     .context("tried to convert an interface to a type literal to merge with a class definition")?;
                 if let Some(b) = b {
                     for el in &b.members {
+                        if let TypeElement::Property(p) = el {
+                            self.report_error_for_conflicting_merged_property(span, &a.body, p);
+                        }
+
                         new_members.extend(self.type_element_to_class_member(el)?);
                     }
 
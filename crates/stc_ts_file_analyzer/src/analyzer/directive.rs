@@ -0,0 +1,165 @@
+//! Handles `// @ts-expect-error` directives.
+use rnode::{Visit, VisitWith};
+use stc_ts_ast_rnode::{RModule, RTsAsExpr, RTsTypeAssertion};
+use stc_ts_errors::Error;
+use swc_common::{comments::Comment, SourceFile, Span, Spanned};
+
+use crate::analyzer::Analyzer;
+
+#[derive(Debug)]
+struct ExpectErrorDirective {
+    /// Zero-based line of the comment.
+    line: usize,
+    span: Span,
+    used: bool,
+}
+
+impl Analyzer<'_, '_> {
+    /// Removes errors expected by `@ts-expect-error` directives in `m`, and
+    /// reports directives which do not match any error.
+    ///
+    /// Only directives for lines with type assertions are handled.
+    ///
+    /// This should be called once, after all statements of the module are
+    /// validated.
+    pub(crate) fn apply_ts_expect_error_directives(&mut self, m: &RModule) {
+        if self.is_builtin || m.span.is_dummy() {
+            return;
+        }
+
+        let fm = self.cm.lookup_source_file(m.span.lo);
+        let mut directives = self.collect_ts_expect_error_directives(&fm);
+        if directives.is_empty() {
+            return;
+        }
+
+        let mut finder = TypeCastFinder { spans: vec![] };
+        m.visit_with(&mut finder);
+        let cast_lines = finder
+            .spans
+            .iter()
+            .filter_map(|span| Some((fm.lookup_line(span.lo)?, fm.lookup_line(span.hi)?)))
+            .collect::<Vec<_>>();
+        directives.retain(|d| match next_code_line(&fm, d.line) {
+            Some(line) => cast_lines.iter().any(|&(lo, hi)| lo <= line && line <= hi),
+            None => false,
+        });
+        if directives.is_empty() {
+            return;
+        }
+
+        let errors = self.storage.take_errors();
+        for err in errors {
+            if !err.is_warning() && !err.is_note() {
+                if let Some(idx) = find_directive_for(&fm, &directives, err.span()) {
+                    directives[idx].used = true;
+                    continue;
+                }
+            }
+
+            self.storage.report(err);
+        }
+
+        for directive in directives {
+            if !directive.used {
+                self.storage.report(Error::UnusedTsExpectError { span: directive.span });
+            }
+        }
+    }
+
+    /// Returns directives in `fm`, sorted by line.
+    fn collect_ts_expect_error_directives(&self, fm: &SourceFile) -> Vec<ExpectErrorDirective> {
+        let mut directives = vec![];
+
+        for comments in [&self.comments.leading, &self.comments.trailing] {
+            for entry in comments.iter() {
+                if *entry.key() < fm.start_pos || fm.end_pos <= *entry.key() {
+                    continue;
+                }
+
+                for cmt in entry.value() {
+                    if !is_ts_expect_error(cmt) {
+                        continue;
+                    }
+
+                    if let Some(line) = fm.lookup_line(cmt.span.hi) {
+                        directives.push(ExpectErrorDirective {
+                            line,
+                            span: cmt.span,
+                            used: false,
+                        });
+                    }
+                }
+            }
+        }
+
+        directives.sort_by_key(|d| d.line);
+        directives.dedup_by_key(|d| d.line);
+        directives
+    }
+}
+
+/// Returns the index of the directive which expects an error at `span`.
+///
+/// Like `tsc`, blank lines and comment lines between the directive and the
+/// error are skipped.
+fn find_directive_for(fm: &SourceFile, directives: &[ExpectErrorDirective], span: Span) -> Option<usize> {
+    if span.is_dummy() || span.lo < fm.start_pos || fm.end_pos <= span.lo {
+        return None;
+    }
+
+    let mut line = fm.lookup_line(span.lo)?;
+    while line > 0 {
+        line -= 1;
+
+        if let Ok(idx) = directives.binary_search_by_key(&line, |d| d.line) {
+            return Some(idx);
+        }
+
+        let text = fm.get_line(line)?;
+        let text = text.trim();
+        if !text.is_empty() && !text.starts_with("//") {
+            return None;
+        }
+    }
+
+    None
+}
+
+/// Returns the first line after `line` which is not blank nor a comment.
+fn next_code_line(fm: &SourceFile, line: usize) -> Option<usize> {
+    let mut line = line + 1;
+    loop {
+        let text = fm.get_line(line)?;
+        let text = text.trim();
+        if !text.is_empty() && !text.starts_with("//") {
+            return Some(line);
+        }
+
+        line += 1;
+    }
+}
+
+struct TypeCastFinder {
+    spans: Vec<Span>,
+}
+
+impl Visit<RTsAsExpr> for TypeCastFinder {
+    fn visit(&mut self, e: &RTsAsExpr) {
+        self.spans.push(e.span);
+        e.visit_children_with(self);
+    }
+}
+
+impl Visit<RTsTypeAssertion> for TypeCastFinder {
+    fn visit(&mut self, e: &RTsTypeAssertion) {
+        self.spans.push(e.span);
+        e.visit_children_with(self);
+    }
+}
+
+fn is_ts_expect_error(cmt: &Comment) -> bool {
+    cmt.text
+        .trim_start_matches(|c: char| c.is_whitespace() || c == '*')
+        .starts_with("@ts-expect-error")
+}
@@ -308,15 +308,13 @@ impl Analyzer<'_, '_> {
                 }) {
                     Some((l, r_ty)) => {
                         if self.ctx.in_cond {
-                            let (name, mut r) = self.calc_type_facts_for_equality(l, r_ty)?;
-                            prevent_generalize(&mut r);
-                            r.make_cheap();
-
                             if op == op!("===") {
-                                self.cur_facts.false_facts.excludes.entry(name.clone()).or_default().push(r.clone());
-
-                                self.add_deep_type_fact(span, name, r, true);
+                                self.add_type_facts_for_strict_equality(span, l, r_ty)?;
                             } else if !is_eq {
+                                let (name, mut r) = self.calc_type_facts_for_equality(l, r_ty)?;
+                                prevent_generalize(&mut r);
+                                r.make_cheap();
+
                                 // Remove from union
                                 self.cur_facts.true_facts.excludes.entry(name.clone()).or_default().push(r.clone());
 
@@ -628,7 +626,7 @@ impl Analyzer<'_, '_> {
 
             op!("in") => {
                 if self.ctx.in_cond {
-                    let left = match &**left {
+                    let property = match &**left {
                         RExpr::Lit(RLit::Str(s)) => Some(s.value.clone()),
                         RExpr::Tpl(t) if t.quasis.len() == 1 => t.quasis[0].cooked.clone().map(|v| v.value),
                         _ => None,
@@ -636,7 +634,12 @@ impl Analyzer<'_, '_> {
                     let name = Name::try_from(&**right).ok();
 
                     if let Some(name) = name {
-                        if let Some(property) = left {
+                        // `#foo in obj`
+                        if let RExpr::PrivateName(p) = &**left {
+                            let new_ty = self.filter_types_with_private_name(&rt, &p.id.sym)?.cheap();
+
+                            self.add_deep_type_fact(span, name, new_ty, true);
+                        } else if let Some(property) = property {
                             let new_ty = self.filter_types_with_property(&rt, &property, None)?.cheap();
 
                             self.add_deep_type_fact(span, name.clone(), new_ty.clone(), true);
@@ -1406,6 +1409,22 @@ impl Analyzer<'_, '_> {
         ty
     }
 
+    /// Adds type facts created by `name === equals_to`.
+    ///
+    /// This does not validate anything, so it can be used to narrow a
+    /// variable which is not in the comparison, like the object of an aliased
+    /// discriminant.
+    pub(crate) fn add_type_facts_for_strict_equality(&mut self, span: Span, name: Name, equals_to: &Type) -> VResult<()> {
+        let (name, mut r) = self.calc_type_facts_for_equality(name, equals_to)?;
+        prevent_generalize(&mut r);
+        r.make_cheap();
+
+        self.cur_facts.false_facts.excludes.entry(name.clone()).or_default().push(r.clone());
+        self.add_deep_type_fact(span, name, r, true);
+
+        Ok(())
+    }
+
     /// We should create a type fact for `foo` in `if (foo.type === 'bar');`.
     fn calc_type_facts_for_equality(&mut self, name: Name, equals_to: &Type) -> VResult<(Name, Type)> {
         let span = equals_to.span();
@@ -15,7 +15,7 @@ use stc_ts_errors::{
 };
 use stc_ts_file_analyzer_macros::extra_validator;
 use stc_ts_generics::type_param::finder::TypeParamUsageFinder;
-use stc_ts_type_ops::{generalization::prevent_generalize, is_str_lit_or_union, Fix};
+use stc_ts_type_ops::{generalization::prevent_generalize, is_str_lit_or_union, this::contains_this, Fix};
 use stc_ts_types::{
     type_id::SymbolId, Alias, Array, Class, ClassDef, ClassMember, ClassProperty, Function, Id, IdCtx, IndexedAccessType, Instance,
     Interface, Intersection, Key, KeywordType, KeywordTypeMetadata, LitType, ModuleId, Ref, Symbol, ThisType, Union, UnionMetadata,
@@ -65,6 +65,7 @@ impl Analyzer<'_, '_> {
             span,
             spread: node.spread,
             ty: box node.expr.validate_with_default(self)?,
+            label: None,
         })
     }
 }
@@ -87,6 +88,10 @@ impl Analyzer<'_, '_> {
 
         let callee = match callee {
             RExprOrSuper::Super(..) => {
+                // Super calls in classes are validated while validating constructors.
+                if !self.ctx.super_references_super_class {
+                    self.storage.report(Error::SuperInNestedFunction { span });
+                }
                 self.report_error_for_super_refs_without_supers(span, true);
                 self.report_error_for_super_reference_in_compute_keys(span, true);
 
@@ -108,7 +113,7 @@ impl Analyzer<'_, '_> {
 
         // TODO(kdy1): validate children
 
-        self.with_child(ScopeKind::Call, Default::default(), |analyzer: &mut Analyzer| {
+        let ret_ty = self.with_child(ScopeKind::Call, Default::default(), |analyzer: &mut Analyzer| {
             analyzer.ctx.is_calling_iife = is_callee_iife;
 
             analyzer.extract_call_new_expr_member(
@@ -120,7 +125,11 @@ impl Analyzer<'_, '_> {
                 type_args.as_ref(),
                 type_ann.as_deref(),
             )
-        })
+        })?;
+
+        self.add_property_from_define_property(e).report(&mut self.storage);
+
+        Ok(ret_ty)
     }
 }
 
@@ -305,11 +314,45 @@ impl Analyzer<'_, '_> {
                     self.storage.report(Error::CannotCallWithNewNonVoidFunction { span })
                 }
 
-                // Symbol uses special type
-                if !args.is_empty() {
-                    unimplemented!("Error reporting for calling `Symbol` with arguments is not implemented yet")
+                // `Symbol(description?: string | number)`
+                if args.len() > 1 {
+                    self.storage.report(Error::ExpectedNArgsButGotM {
+                        span: args[1].expr.span().to(args[args.len() - 1].expr.span()),
+                        min: 0,
+                        max: Some(1),
+                    });
                 }
+                if let Some(arg) = arg_types.first() {
+                    let description_ty = Type::new_union(
+                        span,
+                        vec![
+                            Type::Keyword(KeywordType {
+                                span,
+                                kind: TsKeywordTypeKind::TsStringKeyword,
+                                metadata: Default::default(),
+                            }),
+                            Type::Keyword(KeywordType {
+                                span,
+                                kind: TsKeywordTypeKind::TsNumberKeyword,
+                                metadata: Default::default(),
+                            }),
+                            Type::undefined(span, Default::default()),
+                        ],
+                    );
 
+                    self.assign_with_opts(
+                        &mut Default::default(),
+                        AssignOpts {
+                            span: arg.span,
+                            ..Default::default()
+                        },
+                        &description_ty,
+                        &arg.ty,
+                    )
+                    .report(&mut self.storage);
+                }
+
+                // Symbol uses special type
                 return Ok(Type::Symbol(Symbol {
                     span,
                     id: SymbolId::generate(),
@@ -1160,10 +1203,11 @@ impl Analyzer<'_, '_> {
                         .context("tried to expand ref to handle a spread argument")?;
                     match arg_ty.normalize() {
                         Type::Tuple(arg_ty) => {
-                            new_arg_types.extend(arg_ty.elems.iter().map(|element| &element.ty).cloned().map(|ty| TypeOrSpread {
+                            new_arg_types.extend(arg_ty.elems.iter().map(|element| TypeOrSpread {
                                 span: arg.spread.unwrap(),
                                 spread: None,
-                                ty,
+                                ty: element.ty.clone(),
+                                label: element.label.clone(),
                             }));
                         }
 
@@ -1177,6 +1221,7 @@ impl Analyzer<'_, '_> {
                                 span: *span,
                                 spread: None,
                                 ty: box arg_ty.clone().into_owned(),
+                                label: None,
                             });
                         }
 
@@ -1196,6 +1241,7 @@ impl Analyzer<'_, '_> {
                                 span: arg.span(),
                                 spread: arg.spread,
                                 ty: box elem_type.into_owned(),
+                                label: None,
                             });
                         }
                     }
@@ -2203,6 +2249,11 @@ impl Analyzer<'_, '_> {
 
         let (c, _) = callable.into_iter().next().unwrap();
 
+        if c.type_params.is_some() {
+            self.validate_type_args_count(span, c.type_params.as_deref(), type_args)
+                .report(&mut self.storage);
+        }
+
         if candidates.len() == 1 {
             return self
                 .get_return_type(
@@ -2306,8 +2357,18 @@ impl Analyzer<'_, '_> {
                 FnParam { ty, ..param.clone() }
             })
             .collect_vec();
+        let ret_ty_contains_this = ret_ty.is_conditional() && contains_this(&ret_ty);
         self.expand_this_in_type(&mut ret_ty);
 
+        // `this extends Foo ? A : B` can be evaluated if the receiver is known.
+        //
+        // `this` in the class body is polymorphic, so we don't evaluate it.
+        if ret_ty_contains_this && !matches!(self.scope.this().as_deref().map(Type::normalize), Some(Type::This(..))) {
+            if let Ok(evaluated) = self.normalize(Some(span), Cow::Borrowed(&ret_ty), Default::default()) {
+                ret_ty = evaluated.into_owned();
+            }
+        }
+
         {
             let arg_check_res = self.validate_arg_count(span, &params, args, arg_types, spread_arg_types);
             match arg_check_res {
@@ -2403,6 +2464,13 @@ impl Analyzer<'_, '_> {
             debug!("Inferred types:\n{}", dump_type_map(&self.cm, &inferred.types));
             warn!("Failed to infer types of {:?}", inferred.errored);
 
+            // Calls are validated again below, so errors are reported only by the last
+            // validation.
+            let will_reevaluate = !self.ctx.reevaluating_call_or_new && matches!(expr, ReevalMode::Call(..) | ReevalMode::New(..));
+            if type_args.is_none() && !will_reevaluate {
+                self.report_errors_for_unsatisfied_constraints(span, type_params, &params, args, &inferred.types);
+            }
+
             let expanded_param_types = params
                 .into_iter()
                 .map(|v| -> VResult<_> {
@@ -2548,10 +2616,11 @@ impl Analyzer<'_, '_> {
                     if arg.spread.is_some() {
                         match arg.ty.normalize() {
                             Type::Tuple(arg_ty) => {
-                                new_arg_types.extend(arg_ty.elems.iter().map(|element| &element.ty).cloned().map(|ty| TypeOrSpread {
+                                new_arg_types.extend(arg_ty.elems.iter().map(|element| TypeOrSpread {
                                     span: arg.spread.unwrap(),
                                     spread: None,
-                                    ty,
+                                    ty: element.ty.clone(),
+                                    label: element.label.clone(),
                                 }));
                             }
                             _ => {
@@ -2663,7 +2732,7 @@ impl Analyzer<'_, '_> {
             ty.make_clone_cheap();
 
             if kind == ExtractKind::Call {
-                self.add_call_facts(&expanded_param_types, &args, &mut ty);
+                self.add_call_facts(expr, &expanded_param_types, &args, &mut ty);
             }
 
             return Ok(ty);
@@ -2682,7 +2751,7 @@ impl Analyzer<'_, '_> {
         ret_ty.make_clone_cheap();
 
         if kind == ExtractKind::Call {
-            self.add_call_facts(&params, &args, &mut ret_ty);
+            self.add_call_facts(expr, &params, &args, &mut ret_ty);
         }
 
         return Ok(ret_ty);
@@ -2949,9 +3018,19 @@ impl Analyzer<'_, '_> {
                                     _ => {}
                                 }
 
-                                Error::WrongArgType {
-                                    span: arg.span(),
-                                    inner: box err,
+                                match &arg.label {
+                                    // function f(a: string) {}
+                                    // declare const t: [name: number];
+                                    // f(...t);
+                                    Some(RPat::Ident(label)) => Error::WrongArgTypeOfLabeledElement {
+                                        span: arg.span(),
+                                        label: label.id.sym.clone(),
+                                        inner: box err,
+                                    },
+                                    _ => Error::WrongArgType {
+                                        span: arg.span(),
+                                        inner: box err,
+                                    },
                                 }
                                 .context("tried basical argument assignment")
                             });
@@ -2975,7 +3054,17 @@ impl Analyzer<'_, '_> {
     ///
     /// should make type of `subscriber` `SafeSubscriber`, not `Subscriber`.
     /// I (kdy1) don't know why.
-    fn add_call_facts(&mut self, params: &[FnParam], args: &[RExprOrSpread], ret_ty: &mut Type) {
+    ///
+    /// For `this is T`, the object of the callee is narrowed.
+    ///
+    /// ```ts
+    /// declare const arr: (string | number)[];
+    ///
+    /// if (arr.every((v): v is string => typeof v === "string")) {
+    ///     arr; // string[]
+    /// }
+    /// ```
+    fn add_call_facts(&mut self, expr: ReevalMode, params: &[FnParam], args: &[RExprOrSpread], ret_ty: &mut Type) {
         match ret_ty.normalize() {
             Type::Predicate(p) => {
                 let ty = match &p.ty {
@@ -2984,7 +3073,23 @@ impl Analyzer<'_, '_> {
                 };
 
                 match &p.param_name {
-                    RTsThisTypeOrIdent::TsThisType(this) => {}
+                    RTsThisTypeOrIdent::TsThisType(..) => {
+                        if let ReevalMode::Call(RCallExpr {
+                            callee: RExprOrSuper::Expr(callee),
+                            ..
+                        }) = expr
+                        {
+                            if let RExpr::Member(RMemberExpr {
+                                obj: RExprOrSuper::Expr(obj),
+                                ..
+                            }) = &**callee
+                            {
+                                if let RExpr::Ident(var_name) = &**obj {
+                                    self.store_call_fact_for_var(var_name.span, var_name.into(), &ty);
+                                }
+                            }
+                        }
+                    }
                     RTsThisTypeOrIdent::Ident(arg_id) => {
                         for (idx, param) in params.iter().enumerate() {
                             match &param.pat {
@@ -3137,12 +3242,13 @@ impl Analyzer<'_, '_> {
     ) -> VResult<()> {
         if let Some(type_params) = type_params {
             if let Some(type_args) = type_args {
-                // TODO(kdy1): Handle defaults of the type parameter (Change to range)
-                if type_params.len() != type_args.params.len() {
+                // Type parameters with defaults can be omitted.
+                let min = type_params.iter().filter(|param| param.default.is_none()).count();
+                if type_args.params.len() < min || type_params.len() < type_args.params.len() {
                     return Err(Error::TypeParameterCountMismatch {
                         span,
                         max: type_params.len(),
-                        min: type_params.len(),
+                        min,
                         actual: type_args.params.len(),
                     });
                 }
@@ -3152,6 +3258,50 @@ impl Analyzer<'_, '_> {
         Ok(())
     }
 
+    /// Reports an error if the type inferred for a type parameter does not
+    /// satisfy its constraint.
+    ///
+    /// ```ts
+    /// new WeakRef(1); // `number` is not assignable to `object`
+    /// ```
+    fn report_errors_for_unsatisfied_constraints(
+        &mut self,
+        span: Span,
+        type_params: &[TypeParam],
+        params: &[FnParam],
+        args: &[RExprOrSpread],
+        inferred: &FxHashMap<Id, Type>,
+    ) {
+        for type_param in type_params {
+            let (constraint, ty) = match (type_param.constraint.as_deref(), inferred.get(&type_param.name)) {
+                (Some(constraint), Some(ty)) => (constraint, ty),
+                _ => continue,
+            };
+
+            // Constraints referencing other type parameters are not supported yet.
+            let mut usage = TypeParamUsageFinder::default();
+            constraint.visit_with(&mut usage);
+            ty.visit_with(&mut usage);
+            if !usage.params.is_empty() {
+                continue;
+            }
+
+            let err = match self.assign(span, &mut Default::default(), constraint, ty) {
+                Ok(()) => continue,
+                Err(err) => err,
+            };
+
+            // Report the error at the argument the type is inferred from.
+            let span = params
+                .iter()
+                .zip(args)
+                .find(|(param, _)| matches!(param.ty.normalize(), Type::Param(p) if p.name == type_param.name))
+                .map_or(span, |(_, arg)| arg.span());
+
+            self.storage.report(Error::WrongArgType { span, inner: box err });
+        }
+    }
+
     fn is_subtype_in_fn_call(&mut self, span: Span, arg: &Type, param: &Type) -> bool {
         if arg.type_eq(param) {
             return true;
@@ -3190,6 +3340,14 @@ impl Analyzer<'_, '_> {
             return ArgCheckResult::WrongArgCount;
         }
 
+        // Signatures without type parameters are not selected if type arguments are
+        // given.
+        //
+        // new Array<string>()
+        if type_params.is_none() && type_args.is_some() {
+            return ArgCheckResult::WrongArgCount;
+        }
+
         if self.validate_arg_count(span, params, args, arg_types, spread_arg_types).is_err() {
             return ArgCheckResult::WrongArgCount;
         }
@@ -3203,7 +3361,14 @@ impl Analyzer<'_, '_> {
 
             let mut exact = true;
 
-            for (arg, param) in arg_types.iter().zip(params) {
+            for (idx, arg) in arg_types.iter().enumerate() {
+                let param = match params.get(idx) {
+                    Some(param) => param,
+                    None => match params.last() {
+                        Some(param) if matches!(param.pat, RPat::Rest(..)) => param,
+                        _ => break,
+                    },
+                };
                 // match arg.ty.normalize() {
                 //     Type::Union(..) => match param.ty.normalize() {
                 //         Type::Keyword(..) => if self.assign(&param.ty, &arg.ty, span).is_ok()
@@ -3212,7 +3377,15 @@ impl Analyzer<'_, '_> {
                 //     _ => {}
                 // }
 
-                match param.ty.normalize() {
+                // Each argument is an element of a rest parameter.
+                //
+                // Array(1, 2)
+                let param_ty = match (&param.pat, param.ty.normalize()) {
+                    (RPat::Rest(..), Type::Array(Array { elem_type, .. })) if arg.spread.is_none() => &**elem_type,
+                    _ => &*param.ty,
+                };
+
+                match param_ty.normalize() {
                     Type::Param(..) => {}
                     Type::Instance(param) if param.ty.is_type_param() => {}
                     _ => {
@@ -3225,7 +3398,7 @@ impl Analyzer<'_, '_> {
                                     allow_assignment_to_param: true,
                                     ..Default::default()
                                 },
-                                &param.ty,
+                                param_ty,
                                 &arg.ty,
                             )
                             .is_err()
@@ -3233,7 +3406,7 @@ impl Analyzer<'_, '_> {
                             return ArgCheckResult::ArgTypeMismatch;
                         }
 
-                        if !analyzer.is_subtype_in_fn_call(span, &arg.ty, &param.ty) {
+                        if !analyzer.is_subtype_in_fn_call(span, &arg.ty, param_ty) {
                             exact = false;
                         }
                     }
@@ -3262,6 +3435,7 @@ impl Analyzer<'_, '_> {
                         span: arg.span(),
                         spread: arg.spread,
                         ty: box Type::any(arg.expr.span(), Default::default()),
+                        label: None,
                     })
                 })
                 .collect();
@@ -2,7 +2,8 @@ use stc_ts_ast_rnode::RTsConstAssertion;
 use stc_ts_errors::{DebugExt, Error};
 use stc_ts_file_analyzer_macros::validator;
 use stc_ts_type_ops::{generalization::prevent_generalize, tuple_to_array::prevent_tuple_to_array};
-use stc_ts_types::{Type, TypeParamInstantiation};
+use stc_ts_types::{Operator, PropertySignature, Type, TypeElement, TypeParamInstantiation, Union};
+use swc_ecma_ast::TsTypeOperatorOp;
 
 use crate::{
     analyzer::{expr::TypeOfMode, Analyzer, Ctx},
@@ -33,6 +34,7 @@ impl Analyzer<'_, '_> {
                 .validate_with_args(&mut *a, (mode, None, type_ann))
                 .context("tried to valid expression of a const assertion")?;
 
+            let mut ty = a.widen_for_const_assertion(ty);
             prevent_generalize(&mut ty);
             prevent_tuple_to_array(&mut ty);
 
@@ -45,3 +47,77 @@ impl Analyzer<'_, '_> {
         }
     }
 }
+
+impl Analyzer<'_, '_> {
+    /// Converts the type of an expression to the type of the expression with
+    /// `as const`.
+    ///
+    /// - Tuples and arrays become readonly.
+    /// - Properties of object literals become readonly.
+    ///
+    /// Literals are not generalized by the caller.
+    pub(crate) fn widen_for_const_assertion(&mut self, ty: Type) -> Type {
+        match ty.foldable() {
+            Type::Tuple(mut tuple) => {
+                tuple.elems = tuple
+                    .elems
+                    .into_iter()
+                    .map(|mut elem| {
+                        elem.ty = box self.widen_for_const_assertion(*elem.ty);
+                        elem
+                    })
+                    .collect();
+
+                Type::Operator(Operator {
+                    span: tuple.span,
+                    op: TsTypeOperatorOp::ReadOnly,
+                    ty: box Type::Tuple(tuple),
+                    metadata: Default::default(),
+                })
+            }
+
+            Type::Array(mut array) => {
+                array.elem_type = box self.widen_for_const_assertion(*array.elem_type);
+
+                Type::Operator(Operator {
+                    span: array.span,
+                    op: TsTypeOperatorOp::ReadOnly,
+                    ty: box Type::Array(array),
+                    metadata: Default::default(),
+                })
+            }
+
+            // Already readonly.
+            Type::Operator(
+                op @ Operator {
+                    op: TsTypeOperatorOp::ReadOnly,
+                    ..
+                },
+            ) => Type::Operator(op),
+
+            Type::TypeLit(mut lit) => {
+                lit.members = lit
+                    .members
+                    .into_iter()
+                    .map(|member| match member {
+                        TypeElement::Property(p) => TypeElement::Property(PropertySignature {
+                            readonly: true,
+                            type_ann: p.type_ann.map(|ty| box self.widen_for_const_assertion(*ty)),
+                            ..p
+                        }),
+                        _ => member,
+                    })
+                    .collect();
+
+                Type::TypeLit(lit)
+            }
+
+            Type::Union(union) => Type::Union(Union {
+                types: union.types.into_iter().map(|ty| self.widen_for_const_assertion(ty)).collect(),
+                ..union
+            }),
+
+            ty => ty,
+        }
+    }
+}
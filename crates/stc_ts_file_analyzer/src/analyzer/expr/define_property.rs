@@ -0,0 +1,141 @@
+//! Handles effects of `Object.defineProperty` on the type of the target
+//! object.
+use stc_ts_ast_rnode::{RCallExpr, RExpr, RExprOrSuper, RLit, RMemberExpr, RProp, RPropName, RPropOrSpread, RTsLit};
+use stc_ts_types::{Id, Key, LitType, PropertySignature, TypeElement, TypeLit};
+use swc_atoms::js_word;
+use swc_common::Spanned;
+
+use crate::{
+    analyzer::{expr::TypeOfMode, scope::VarInfo, Analyzer},
+    ty::{Type, TypeExt},
+    VResult,
+};
+
+impl Analyzer<'_, '_> {
+    /// Adds the property defined by
+    /// `Object.defineProperty(obj, 'key', { value: 1 })` to the type of `obj`.
+    ///
+    /// Only string literal keys and literal values are handled. Accessor
+    /// descriptors (`get` / `set`) are ignored.
+    pub(super) fn add_property_from_define_property(&mut self, e: &RCallExpr) -> VResult<()> {
+        if !is_object_define_property(&e.callee) || e.args.len() < 3 || e.args.iter().any(|arg| arg.spread.is_some()) {
+            return Ok(());
+        }
+
+        let obj = match &*e.args[0].expr {
+            RExpr::Ident(i) => i,
+            _ => return Ok(()),
+        };
+        let key = match &*e.args[1].expr {
+            RExpr::Lit(RLit::Str(s)) => Key::Normal {
+                span: s.span,
+                sym: s.value.clone(),
+            },
+            _ => return Ok(()),
+        };
+        let desc = match &*e.args[2].expr {
+            RExpr::Object(desc) => desc,
+            _ => return Ok(()),
+        };
+
+        let mut value = None;
+        let mut writable = false;
+        for prop in &desc.props {
+            let kv = match prop {
+                RPropOrSpread::Prop(prop) => match &**prop {
+                    RProp::KeyValue(kv) => kv,
+                    // Getters and setters make this an accessor descriptor.
+                    _ => return Ok(()),
+                },
+                RPropOrSpread::Spread(..) => return Ok(()),
+            };
+            let name = match &kv.key {
+                RPropName::Ident(i) => &i.sym,
+                RPropName::Str(s) => &s.value,
+                _ => return Ok(()),
+            };
+
+            match &**name {
+                "value" => value = Some(&*kv.value),
+                "writable" => writable = matches!(&*kv.value, RExpr::Lit(RLit::Bool(b)) if b.value),
+                "get" | "set" => return Ok(()),
+                _ => {}
+            }
+        }
+
+        let value = match value {
+            Some(value) => value,
+            None => return Ok(()),
+        };
+        let lit = match value {
+            RExpr::Lit(RLit::Str(s)) => RTsLit::Str(s.clone()),
+            RExpr::Lit(RLit::Num(n)) => RTsLit::Number(n.clone()),
+            RExpr::Lit(RLit::Bool(b)) => RTsLit::Bool(b.clone()),
+            _ => return Ok(()),
+        };
+        let mut value_ty = Type::Lit(LitType {
+            span: value.span(),
+            lit,
+            metadata: Default::default(),
+        });
+        if writable {
+            value_ty = value_ty.generalize_lit();
+        }
+
+        let obj_ty = self.type_of_var(obj, TypeOfMode::RValue, None)?;
+        let prop_ty = Type::TypeLit(TypeLit {
+            span: e.span,
+            members: vec![TypeElement::Property(PropertySignature {
+                span: e.span,
+                accessibility: None,
+                readonly: !writable,
+                key,
+                optional: false,
+                params: Default::default(),
+                type_ann: Some(box value_ty),
+                type_params: Default::default(),
+                metadata: Default::default(),
+                accessor: Default::default(),
+            })],
+            metadata: Default::default(),
+        });
+
+        let mut new_ty = Type::new_intersection(e.span, vec![obj_ty, prop_ty]);
+        new_ty.make_clone_cheap();
+
+        let id: Id = obj.into();
+        if let Some(var_info) = self.scope.get_var_mut(&id) {
+            var_info.actual_ty = Some(new_ty);
+            return Ok(());
+        }
+
+        if let Some(var_info) = self.scope.search_parent(&id) {
+            let var_info = VarInfo {
+                actual_ty: Some(new_ty),
+                copied: true,
+                ..var_info.clone()
+            };
+            self.scope.insert_var(id, var_info);
+        }
+
+        Ok(())
+    }
+}
+
+fn is_object_define_property(callee: &RExprOrSuper) -> bool {
+    match callee {
+        RExprOrSuper::Expr(callee) => match &**callee {
+            RExpr::Member(RMemberExpr {
+                obj: RExprOrSuper::Expr(obj),
+                prop,
+                computed: false,
+                ..
+            }) => match (&**obj, &**prop) {
+                (RExpr::Ident(obj), RExpr::Ident(prop)) => obj.sym == js_word!("Object") && &*prop.sym == "defineProperty",
+                _ => false,
+            },
+            _ => false,
+        },
+        _ => false,
+    }
+}
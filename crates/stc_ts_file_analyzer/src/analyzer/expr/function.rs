@@ -133,6 +133,7 @@ impl Analyzer<'_, '_> {
 
                 f.params.validate_with(&mut *child.with_ctx(ctx))?
             };
+            child.report_error_for_misplaced_rest_params(&params);
 
             let declared_ret_ty = match f.return_type.validate_with(child) {
                 Some(Ok(ty)) => Some(ty),
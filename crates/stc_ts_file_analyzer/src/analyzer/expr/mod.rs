@@ -8,8 +8,9 @@ use std::{
 use optional_chaining::is_obj_opt_chaining;
 use rnode::{NodeId, VisitWith};
 use stc_ts_ast_rnode::{
-    RAssignExpr, RBindingIdent, RClassExpr, RExpr, RExprOrSuper, RIdent, RInvalid, RLit, RMemberExpr, RNull, RNumber, RParenExpr, RPat,
-    RPatOrExpr, RSeqExpr, RStr, RSuper, RThisExpr, RTpl, RTsEntityName, RTsEnumMemberId, RTsLit, RTsNonNullExpr, RUnaryExpr,
+    RAssignExpr, RBindingIdent, RClassExpr, RExpr, RExprOrSuper, RIdent, RInvalid, RLit, RMemberExpr, RNull, RNumber, RObjectPatProp,
+    RParenExpr, RPat, RPatOrExpr, RPrivateName, RSeqExpr, RStr, RSuper, RThisExpr, RTpl, RTsEntityName, RTsEnumMemberId, RTsLit,
+    RTsNonNullExpr, RUnaryExpr,
 };
 use stc_ts_base_type_ops::bindings::BindingKind;
 use stc_ts_errors::{
@@ -32,6 +33,7 @@ use tracing::{debug, info, warn, Level};
 use ty::TypeExt;
 
 use self::bin::extract_name_for_assignment;
+pub use self::type_cast::CastKind;
 pub(crate) use self::{array::GetIteratorOpts, call_new::CallOpts};
 use crate::{
     analyzer::{
@@ -59,11 +61,14 @@ mod bin;
 mod call_new;
 mod const_assertion;
 mod constraint_reducer;
+mod define_property;
 mod function;
 mod jsx;
 mod meta_prop;
 mod object;
 pub(crate) mod optional_chaining;
+#[cfg(test)]
+mod tests;
 mod type_cast;
 mod unary;
 mod update;
@@ -216,6 +221,13 @@ impl Analyzer<'_, '_> {
                         metadata: Default::default(),
                     }));
                 }
+                RExpr::Lit(RLit::BigInt(v)) => {
+                    return Ok(Type::Lit(LitType {
+                        span: v.span,
+                        lit: RTsLit::BigInt(v.clone()),
+                        metadata: Default::default(),
+                    }));
+                }
                 RExpr::Lit(RLit::Null(RNull { span })) => {
                     if self.ctx.in_export_default_expr {
                         // TODO(kdy1): strict mode
@@ -298,6 +310,12 @@ impl Analyzer<'_, '_> {
 
                 RExpr::Invalid(ref i) => return Ok(Type::any(i.span(), Default::default())),
 
+                // `#foo in obj`
+                RExpr::PrivateName(ref p) => {
+                    self.report_error_for_private_name_outside_class(p);
+                    return Ok(Type::any(p.span, Default::default()));
+                }
+
                 RExpr::OptChain(expr) => expr.validate_with_args(self, type_ann),
 
                 RExpr::TsConstAssertion(expr) => expr.validate_with_args(self, (mode, None, type_ann)),
@@ -623,6 +641,19 @@ impl Analyzer<'_, '_> {
         true
     }
 
+    /// Reports an error if `#foo` is used outside of the classes declaring it.
+    pub(crate) fn report_error_for_private_name_outside_class(&mut self, p: &RPrivateName) {
+        if self.is_builtin || self.scope.is_private_name_declared(&p.id.sym) {
+            return;
+        }
+
+        if self.scope.first_kind(|kind| kind == ScopeKind::Class).is_some() {
+            self.storage.report(Error::CannotAccessPrivatePropertyFromOutside { span: p.span });
+        } else {
+            self.storage.report(Error::PrivateIdentifierOutsideClass { span: p.span });
+        }
+    }
+
     #[cfg_attr(debug_assertions, tracing::instrument(skip_all))]
     pub(crate) fn validate_key(&mut self, prop: &RExpr, computed: bool) -> VResult<Key> {
         if computed {
@@ -927,14 +958,17 @@ impl Analyzer<'_, '_> {
                     readonly,
                     ..
                 }) => {
-                    has_index_signature = true;
-
                     if params.len() != 1 {
                         unimplemented!("Index signature with multiple parameters")
                     }
 
                     let index_ty = &params[0].ty;
 
+                    // A template literal index signature applies only to matching keys.
+                    if !matches!(index_ty.normalize(), Type::Tpl(..)) {
+                        has_index_signature = true;
+                    }
+
                     let prop_ty = prop.ty();
 
                     // Don't know exact reason, but you can index `{ [x: string]: boolean }`
@@ -1091,6 +1125,18 @@ impl Analyzer<'_, '_> {
                     }
                 }
 
+                // `obj[k]` where `k: keyof typeof obj` results in the union of the types of
+                // all properties.
+                Type::Operator(Operator {
+                    op: TsTypeOperatorOp::KeyOf,
+                    ty: keyof_ty,
+                    ..
+                }) if type_mode == TypeOfMode::RValue && !opts.for_validation_of_indexed_access_type => {
+                    if let Ok(Some(ty)) = self.access_property_by_keys_of(span, obj, keyof_ty, type_mode, id_ctx, opts) {
+                        return Ok(ty);
+                    }
+                }
+
                 Type::Param(TypeParam {
                     constraint: Some(constraint),
                     ..
@@ -1166,6 +1212,50 @@ impl Analyzer<'_, '_> {
         Ok(ty)
     }
 
+    /// Returns the union of the types of properties of `obj` accessed by each
+    /// key of `keyof keyof_ty`.
+    ///
+    /// Returns [None] if the keys are not known.
+    fn access_property_by_keys_of(
+        &mut self,
+        span: Span,
+        obj: &Type,
+        keyof_ty: &Type,
+        type_mode: TypeOfMode,
+        id_ctx: IdCtx,
+        opts: AccessPropertyOpts,
+    ) -> VResult<Option<Type>> {
+        let keys = self.keyof(span, keyof_ty)?;
+        let keys = match keys.normalize() {
+            Type::Union(u) => u.types.clone(),
+            _ => vec![keys.clone()],
+        };
+
+        let mut types = vec![];
+        for key in keys {
+            let key = match key.normalize() {
+                Type::Lit(LitType { lit: RTsLit::Str(s), .. }) => Key::Normal {
+                    span: s.span,
+                    sym: s.value.clone(),
+                },
+                Type::Lit(LitType {
+                    lit: RTsLit::Number(n), ..
+                }) => Key::Num(n.clone()),
+                _ => return Ok(None),
+            };
+
+            types.push(self.access_property(span, obj, &key, type_mode, id_ctx, opts)?);
+        }
+
+        if types.is_empty() {
+            return Ok(None);
+        }
+
+        types.dedup_type();
+
+        Ok(Some(Type::union(types)))
+    }
+
     fn access_property_inner(
         &mut self,
         span: Span,
@@ -2652,6 +2742,10 @@ impl Analyzer<'_, '_> {
                 return Ok(ty);
             }
 
+            _ if obj.is_global_this() => {
+                return self.access_property_of_global_this(span, obj, prop);
+            }
+
             Type::Query(QueryType {
                 expr: box QueryExpr::TsEntityName(name),
                 ..
@@ -2858,6 +2952,40 @@ impl Analyzer<'_, '_> {
         }
     }
 
+    /// Properties of `globalThis` are global variables, including ones declared
+    /// in `declare global {}`.
+    fn access_property_of_global_this(&mut self, span: Span, obj: &Type, prop: &Key) -> VResult {
+        let sym = match prop {
+            Key::Normal { sym, .. } => Some(sym),
+            Key::Computed(ComputedKey { ty, .. }) => match ty.normalize() {
+                Type::Lit(LitType { lit: RTsLit::Str(s), .. }) => Some(&s.value),
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(sym) = sym {
+            if let Ok(ty) = self.env.get_global_var(span, sym) {
+                return Ok(ty);
+            }
+        }
+
+        // `globalThis["foo"]` is `any` unless `noImplicitAny` is enabled, but
+        // `globalThis.foo` is always an error.
+        if !prop.is_computed() {
+            return Err(Error::NoSuchProperty {
+                span,
+                obj: Some(box obj.clone()),
+                prop: Some(box prop.clone()),
+            });
+        }
+
+        if self.rule().no_implicit_any {
+            return Err(Error::ImplicitAnyBecauseGlobalThisHasNoIndexSignature { span });
+        }
+
+        Ok(Type::any(span, Default::default()))
+    }
+
     /// Returned type reflects conditional type facts.
     #[cfg_attr(debug_assertions, tracing::instrument(skip_all))]
     pub(super) fn type_of_var(&mut self, i: &RIdent, type_mode: TypeOfMode, type_args: Option<&TypeParamInstantiation>) -> VResult {
@@ -3153,6 +3281,17 @@ impl Analyzer<'_, '_> {
 
                 return Ok(ty);
             }
+
+            if &*i.sym == "globalThis" {
+                return Ok(Type::Query(QueryType {
+                    span,
+                    expr: box QueryExpr::TsEntityName(RTsEntityName::Ident(RIdent::new(
+                        "globalThis".into(),
+                        span.with_ctxt(SyntaxContext::empty()),
+                    ))),
+                    metadata: Default::default(),
+                }));
+            }
         }
 
         // Check `declaring` before checking variables.
@@ -3505,6 +3644,10 @@ impl Analyzer<'_, '_> {
             ..
         } = *expr;
 
+        if let (false, RExpr::PrivateName(p)) = (computed, &**prop) {
+            self.report_error_for_private_name_outside_class(p);
+        }
+
         let name: Option<Name> = expr.try_into().ok();
 
         if let TypeOfMode::RValue = type_mode {
@@ -3559,11 +3702,22 @@ impl Analyzer<'_, '_> {
 
                 self.report_error_for_super_reference_in_compute_keys(span, false);
 
-                if let Some(v) = self.scope.get_super_class() {
-                    v.clone()
-                } else {
-                    self.storage.report(Error::SuperInClassWithoutSuper { span });
-                    Type::any(span, Default::default())
+                match self.scope.super_container_kind() {
+                    Some(ScopeKind::Class) => {
+                        if let Some(v) = self.scope.get_super_class() {
+                            v.clone()
+                        } else {
+                            self.storage.report(Error::SuperInClassWithoutSuper { span });
+                            Type::any(span, Default::default())
+                        }
+                    }
+                    // `super` in methods of object literals refers to the prototype of the
+                    // object.
+                    Some(_) => Type::any(span, Default::default()),
+                    None => {
+                        self.storage.report(Error::SuperOutsideClassOrObjectLit { span });
+                        Type::any(span, Default::default())
+                    }
                 }
             }
         };
@@ -3809,15 +3963,33 @@ fn is_valid_lhs(l: &RPatOrExpr) -> Result<(), Error> {
         match e {
             RExpr::Ident(..) | RExpr::Member(..) => Ok(()),
             RExpr::Paren(e) => is_valid_lhs_expr(&e.expr),
+            // (x as any) = 1;
+            // x! = 1;
+            RExpr::TsAs(e) => is_valid_lhs_expr(&e.expr),
+            RExpr::TsTypeAssertion(e) => is_valid_lhs_expr(&e.expr),
+            RExpr::TsNonNull(e) => is_valid_lhs_expr(&e.expr),
             _ => Err(Error::InvalidLhsOfAssign { span: e.span() }),
         }
     }
 
-    match l {
-        RPatOrExpr::Pat(pat) => match &**pat {
+    // [a, f()] = arr;
+    fn is_valid_lhs_pat(p: &RPat) -> Result<(), Error> {
+        match p {
             RPat::Expr(e) => is_valid_lhs_expr(&e),
-            _ => Ok(()),
-        },
+            RPat::Array(p) => p.elems.iter().flatten().try_for_each(is_valid_lhs_pat),
+            RPat::Object(p) => p.props.iter().try_for_each(|prop| match prop {
+                RObjectPatProp::KeyValue(prop) => is_valid_lhs_pat(&prop.value),
+                RObjectPatProp::Rest(prop) => is_valid_lhs_pat(&prop.arg),
+                RObjectPatProp::Assign(..) => Ok(()),
+            }),
+            RPat::Assign(p) => is_valid_lhs_pat(&p.left),
+            RPat::Rest(p) => is_valid_lhs_pat(&p.arg),
+            RPat::Ident(..) | RPat::Invalid(..) => Ok(()),
+        }
+    }
+
+    match l {
+        RPatOrExpr::Pat(pat) => is_valid_lhs_pat(&pat),
         RPatOrExpr::Expr(e) => is_valid_lhs_expr(&e),
     }
 }
@@ -0,0 +1,464 @@
+use rnode::VisitWith;
+use stc_ts_ast_rnode::{RExpr, RExprStmt, RModuleItem, RStmt, RTsAsExpr};
+use stc_ts_errors::{DebugContext, Error};
+use stc_ts_types::{EnumVariant, Id, ModuleId};
+use swc_common::{Spanned, DUMMY_SP};
+
+use super::type_cast::{literal_overlaps_keyword, CastTargetCache};
+use crate::{
+    analyzer::{
+        tests::{run_test, test_two},
+        CastKind,
+    },
+    ty::Type,
+    validator::ValidateWith,
+};
+
+#[track_caller]
+fn assert_cast_kinds(src: &str, expected: Vec<Option<CastKind>>) {
+    run_test(|tester| {
+        let module = tester.parse("main.ts", src);
+        module.visit_with(&mut tester.analyzer);
+
+        let actual = module
+            .body
+            .iter()
+            .filter_map(|item| match item {
+                RModuleItem::Stmt(RStmt::Expr(RExprStmt { expr, .. })) => match &**expr {
+                    RExpr::TsAs(RTsAsExpr { span, .. }) => Some(tester.analyzer.cast_kind(*span)),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(expected, actual);
+    })
+    .unwrap();
+}
+
+#[test]
+fn cast_kind_1() {
+    assert_cast_kinds(
+        "
+    declare const a: string | number;
+    declare const b: any;
+
+    a as string | number;
+    a as string;
+    'foo' as string;
+    b as string;
+    a as boolean;
+    ",
+        vec![
+            Some(CastKind::Identity),
+            Some(CastKind::Subtype),
+            Some(CastKind::Supertype),
+            Some(CastKind::Any),
+            None,
+        ],
+    );
+}
+
+#[test]
+fn cast_kind_2() {
+    assert_cast_kinds(
+        "
+    interface A {
+        a: string;
+    }
+    interface B {
+        a: string;
+        b: number;
+    }
+    declare const a: A;
+    declare const b: B;
+    declare const c: unknown;
+
+    b as A;
+    a as B;
+    c as A;
+    ",
+        vec![Some(CastKind::Supertype), Some(CastKind::Subtype), Some(CastKind::Subtype)],
+    );
+}
+
+#[test]
+fn cast_kind_number_bigint() {
+    assert_cast_kinds(
+        "
+    declare const n: number;
+    declare const b: bigint;
+
+    0n as number;
+    0 as bigint;
+    n as bigint;
+    b as number;
+    0n as bigint;
+    0 as number;
+    ",
+        vec![None, None, None, None, Some(CastKind::Supertype), Some(CastKind::Supertype)],
+    );
+}
+
+#[test]
+fn cast_kind_unresolved_target() {
+    assert_cast_kinds(
+        "
+    declare const a: string;
+
+    a as NotFound;
+    a as NotFound<number>;
+    a as string;
+    ",
+        vec![None, None, Some(CastKind::Identity)],
+    );
+}
+
+#[test]
+fn cast_kind_boolean_and_true_or_false() {
+    assert_cast_kinds(
+        "
+    declare const a: boolean;
+    declare const b: true | false;
+
+    a as true | false;
+    b as boolean;
+    ",
+        vec![Some(CastKind::Identity), Some(CastKind::Identity)],
+    );
+}
+
+#[test]
+fn cast_kind_unknown_array() {
+    assert_cast_kinds(
+        "
+    declare const a: string[];
+    declare const b: unknown[];
+    declare const c: any[];
+
+    a as unknown[];
+    b as string[];
+    c as string[];
+    ",
+        vec![Some(CastKind::Supertype), Some(CastKind::Subtype), Some(CastKind::Any)],
+    );
+}
+
+#[test]
+fn wrapped_cast_warning_is_warning() {
+    let err = Error::DebugContext(DebugContext {
+        span: DUMMY_SP,
+        context: "tried to validate a type cast".into(),
+        inner: box Error::NonOverlappingTypeCastWarning { span: DUMMY_SP },
+    });
+
+    assert!(err.is_warning());
+}
+
+fn tuple_elems(ty: &Type) -> Vec<Type> {
+    match ty.normalize() {
+        Type::Alias(alias) => tuple_elems(&alias.ty),
+        Type::Tuple(tuple) => tuple.elems.iter().map(|el| (*el.ty).clone()).collect(),
+        _ => unreachable!("{:?} is not a tuple", ty),
+    }
+}
+
+#[test]
+fn literal_overlaps_keyword_of_all_primitives() {
+    // `(kind, is_literal)` of each element of the first tuple.
+    let kinds = [
+        (0, false),
+        (0, true),
+        (0, true),
+        (1, false),
+        (1, true),
+        (1, true),
+        (2, false),
+        (2, true),
+        (2, true),
+        (3, false),
+        (3, true),
+        (3, true),
+    ];
+
+    test_two(
+        "[number, 1, 2, string, 'a', 'b', boolean, true, false, bigint, 1n, 2n]",
+        "[symbol, null, undefined, object, {}, number[]]",
+        |_, primitives, others| {
+            let primitives = tuple_elems(&primitives);
+            let mut others = tuple_elems(&others);
+            assert_eq!(primitives.len(), kinds.len());
+
+            for (i, from) in primitives.iter().enumerate() {
+                for (j, to) in primitives.iter().enumerate() {
+                    let expected = if kinds[i].0 != kinds[j].0 {
+                        false
+                    } else if kinds[i].1 && kinds[j].1 {
+                        i == j
+                    } else {
+                        true
+                    };
+
+                    assert_eq!(literal_overlaps_keyword(from, to), Some(expected), "{:?} and {:?}", from, to);
+                }
+            }
+
+            // Enum-backed literals are handled by the enum-aware path.
+            others.push(Type::EnumVariant(EnumVariant {
+                span: DUMMY_SP,
+                ctxt: ModuleId::builtin(),
+                enum_name: Id::word("E".into()),
+                name: Some("A".into()),
+                metadata: Default::default(),
+            }));
+
+            for other in &others {
+                for primitive in &primitives {
+                    assert_eq!(literal_overlaps_keyword(other, primitive), None, "{:?} and {:?}", other, primitive);
+                    assert_eq!(literal_overlaps_keyword(primitive, other), None, "{:?} and {:?}", primitive, other);
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn validate_casts_with_repeated_targets() {
+    test_two("{ a: string }", "{ a: string; b: number }", |analyzer, l, r| {
+        let casts = (0..1000)
+            .flat_map(|_| vec![(DUMMY_SP, l.clone(), r.clone()), (DUMMY_SP, r.clone(), l.clone())])
+            .collect::<Vec<_>>();
+
+        let results = analyzer.validate_casts_with_notes(&casts);
+
+        assert_eq!(results.len(), 2000);
+        for (res, notes) in results {
+            assert!(res.is_ok(), "{:?}", res);
+            assert!(notes.is_empty(), "{:?}", notes);
+        }
+    });
+}
+
+#[test]
+fn validate_casts_with_repeated_invalid_targets() {
+    test_two("string", "number", |analyzer, l, r| {
+        let casts = (0..1000).map(|_| (DUMMY_SP, l.clone(), r.clone())).collect::<Vec<_>>();
+
+        let results = analyzer.validate_casts(&casts);
+
+        assert_eq!(results.len(), 1000);
+        for res in results {
+            assert!(res.is_err(), "{:?}", res);
+        }
+    });
+}
+
+#[test]
+fn validate_casts_does_not_report_notes() {
+    test_two("0", "0n", |analyzer, l, r| {
+        let mut results = analyzer.validate_casts_with_notes(&[(l.span(), l.clone(), r.clone())]);
+
+        assert_eq!(results.len(), 1);
+        let (res, notes) = results.remove(0);
+        let notes: Vec<Error> = notes.into();
+        assert!(res.is_err());
+        assert!(notes.iter().any(|err| matches!(err.actual(), Error::NumberBigIntCast { .. })));
+
+        let reported: Vec<Error> = analyzer.storage.take_errors().into();
+        assert!(reported.is_empty(), "{:?}", reported);
+    });
+}
+
+#[test]
+fn cast_target_cache_resolves_each_target_once() {
+    test_two("{ a: string }", "{ a: string; b: number }", |_, l, r| {
+        let mut cache = CastTargetCache::default();
+        let mut resolved = 0;
+
+        for _ in 0..100 {
+            for target in [&l, &r] {
+                cache.get_or_insert_with(target, |target| {
+                    resolved += 1;
+                    target.clone()
+                });
+            }
+        }
+
+        assert_eq!(resolved, 2);
+        assert_eq!(cache.num_targets(), 2);
+    });
+}
+
+#[test]
+fn cast_target_cache_shares_targets_of_different_casts() {
+    run_test(|tester| {
+        let module = tester.parse(
+            "main.ts",
+            "
+    interface Foo {
+        a: string;
+    }
+    declare const v: unknown;
+
+    v as Foo;
+    v as Foo;
+    ",
+        );
+        module.visit_with(&mut tester.analyzer);
+
+        let targets = module
+            .body
+            .iter()
+            .filter_map(|item| match item {
+                RModuleItem::Stmt(RStmt::Expr(RExprStmt { expr, .. })) => match &**expr {
+                    RExpr::TsAs(RTsAsExpr { type_ann, .. }) => Some(type_ann.validate_with(&mut tester.analyzer).unwrap()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(targets.len(), 2);
+        assert_ne!(targets[0].span(), targets[1].span());
+
+        let mut cache = CastTargetCache::default();
+        let mut resolved = 0;
+        for target in &targets {
+            cache.get_or_insert_with(target, |target| {
+                resolved += 1;
+                target.clone()
+            });
+        }
+
+        assert_eq!(resolved, 1);
+        assert_eq!(cache.num_targets(), 1);
+    })
+    .unwrap();
+}
+
+#[test]
+fn spread_of_labeled_tuple_reports_label() {
+    run_test(|tester| {
+        let module = tester.parse(
+            "main.ts",
+            "
+    function f(a: string, b: number) {}
+
+    declare const labeled: [first: number, second: number];
+    declare const unlabeled: [number, number];
+
+    f(...labeled);
+    f(...unlabeled);
+    ",
+        );
+        module.visit_with(&mut tester.analyzer);
+
+        let errors: Vec<Error> = tester.analyzer.storage.take_errors().into();
+        let labels = errors
+            .iter()
+            .filter_map(|err| match err.actual() {
+                Error::WrongArgTypeOfLabeledElement { label, .. } => Some(label.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let unlabeled = errors
+            .iter()
+            .filter(|err| matches!(err.actual(), Error::WrongArgType { .. }))
+            .count();
+
+        assert_eq!(labels, vec!["first".to_string()]);
+        assert_eq!(unlabeled, 1);
+    })
+    .unwrap();
+}
+
+#[test]
+fn cast_of_array_literal_to_shorter_tuple_labels_excess_elements() {
+    run_test(|tester| {
+        let module = tester.parse(
+            "main.ts",
+            "
+    [1, 2, 3] as [number];
+    ",
+        );
+        module.visit_with(&mut tester.analyzer);
+
+        let elems = match &module.body[0] {
+            RModuleItem::Stmt(RStmt::Expr(RExprStmt { expr, .. })) => match &**expr {
+                RExpr::TsAs(RTsAsExpr { expr, .. }) => match &**expr {
+                    RExpr::Array(arr) => arr.elems.iter().map(|elem| elem.span()).collect::<Vec<_>>(),
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+
+        let errors: Vec<Error> = tester.analyzer.storage.take_errors().into();
+        let excess = errors
+            .iter()
+            .find_map(|err| match err.actual() {
+                Error::TupleCastWithExcessElements {
+                    excess,
+                    source_len,
+                    target_len,
+                    ..
+                } => {
+                    assert_eq!((*source_len, *target_len), (3, 1));
+                    Some(excess.clone())
+                }
+                _ => None,
+            })
+            .expect("should report excess elements");
+
+        assert_eq!(excess, elems[1..].to_vec());
+    })
+    .unwrap();
+}
+
+#[test]
+fn cast_of_declared_tuple_to_shorter_tuple_is_invalid_tuple_cast() {
+    run_test(|tester| {
+        let module = tester.parse(
+            "main.ts",
+            "
+    declare const t: [number, number, number];
+
+    t as [number];
+    ",
+        );
+        module.visit_with(&mut tester.analyzer);
+
+        let errors: Vec<Error> = tester.analyzer.storage.take_errors().into();
+
+        assert!(errors.iter().any(|err| matches!(err.actual(), Error::InvalidTupleCast { .. })));
+        assert!(!errors
+            .iter()
+            .any(|err| matches!(err.actual(), Error::TupleCastWithExcessElements { .. })));
+    })
+    .unwrap();
+}
+
+#[test]
+fn unsatisfied_constraint_with_callback_is_reported_once() {
+    run_test(|tester| {
+        let module = tester.parse(
+            "main.ts",
+            "
+    declare function f<T extends object>(value: T, cb: (value: T) => void): void;
+
+    f(1, (v) => {});
+    ",
+        );
+        module.visit_with(&mut tester.analyzer);
+
+        let errors: Vec<Error> = tester.analyzer.storage.take_errors().into();
+        let wrong_args = errors
+            .iter()
+            .filter(|err| matches!(err.actual(), Error::WrongArgType { .. }))
+            .count();
+
+        assert_eq!(wrong_args, 1, "{:?}", errors);
+    })
+    .unwrap();
+}
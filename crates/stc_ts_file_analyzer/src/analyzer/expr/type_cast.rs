@@ -1,11 +1,21 @@
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    mem::{discriminant, Discriminant},
+};
 
-use stc_ts_ast_rnode::{RTsAsExpr, RTsLit, RTsTypeAssertion};
-use stc_ts_errors::{DebugExt, Error};
-use stc_ts_types::{Interface, KeywordType, LitType, TypeElement, TypeParamInstantiation};
+use fxhash::FxHashMap;
+use stc_ts_ast_rnode::{RBindingIdent, RExpr, RIdent, RPat, RTsAsExpr, RTsEntityName, RTsLit, RTsTypeAssertion};
+use stc_ts_errors::{DebugExt, Error, Errors};
+use stc_ts_types::{
+    Alias, Array, ClassDef, ClassMember, ClassProperty, Enum, EnumVariant, FnParam, Function, ImportType, Interface, Intersection, Key,
+    KeywordType, LitType, Method, ModuleId, Operator, PropertySignature, QueryExpr, QueryType, Ref, RestType, TsExpr, Tuple, TupleElement,
+    TypeElement, TypeLit, TypeParam, TypeParamInstantiation,
+};
 use stc_utils::cache::Freeze;
+use swc_atoms::{js_word, JsWord};
 use swc_common::{Span, Spanned, TypeEq};
-use swc_ecma_ast::TsKeywordTypeKind;
+use swc_ecma_ast::{Accessibility, TsKeywordTypeKind, TsTypeOperatorOp};
+use tracing::debug;
 
 use crate::{
     analyzer::{
@@ -16,12 +26,29 @@ use crate::{
         Analyzer,
     },
     ty::Type,
-    util::is_str_or_union,
+    util::{is_str_or_union, unwrap_ref_with_single_arg},
     validator,
     validator::ValidateWith,
     VResult,
 };
 
+/// The reason a type assertion is allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastKind {
+    /// The type of the expression is identical to the target type.
+    Identity,
+    /// The target type is a subtype of the type of the expression, so the
+    /// cast narrows the type.
+    Subtype,
+    /// The target type is a supertype of the type of the expression, so the
+    /// cast widens the type.
+    Supertype,
+    /// The types are not related, but they overlap.
+    Overlap,
+    /// One of the types is `any`.
+    Any,
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub(crate) struct CastableOpts {
     /// `true` if we are checking for `A extends B` relation.
@@ -47,7 +74,9 @@ impl Analyzer<'_, '_> {
         let mut orig_ty = e.expr.validate_with_args(self, (mode, type_args, Some(&casted_ty)))?;
         orig_ty.make_clone_cheap();
 
-        self.validate_type_cast(e.span, orig_ty, casted_ty)
+        self.report_excess_properties_in_type_cast(e.span, &e.expr, &orig_ty, &casted_ty);
+
+        self.validate_type_cast(e.span, &e.expr, orig_ty, casted_ty)
     }
 }
 
@@ -68,11 +97,99 @@ impl Analyzer<'_, '_> {
         let casted_ty = e.type_ann.validate_with(self)?;
         let orig_ty = e.expr.validate_with_args(self, (mode, type_args, Some(&casted_ty)))?;
 
-        self.validate_type_cast(e.span, orig_ty, casted_ty)
+        self.report_excess_properties_in_type_cast(e.span, &e.expr, &orig_ty, &casted_ty);
+
+        self.validate_type_cast(e.span, &e.expr, orig_ty, casted_ty)
     }
 }
 
 impl Analyzer<'_, '_> {
+    /// Emits a note for each element whose label is changed by a type cast.
+    ///
+    /// ```ts
+    /// declare let t: [x: number];
+    /// t as [y: number];
+    /// ```
+    fn note_tuple_label_changes(&mut self, span: Span, from: &[TupleElement], to: &[TupleElement]) {
+        for (from, to) in from.iter().zip(to) {
+            if let (Some(RPat::Ident(RBindingIdent { id: from_label, .. })), Some(RPat::Ident(RBindingIdent { id: to_label, .. }))) =
+                (&from.label, &to.label)
+            {
+                if from_label.sym != to_label.sym {
+                    self.storage.report(Error::TupleLabelChangedByCast { span });
+                }
+            }
+        }
+    }
+
+    /// Reports excess properties of an object literal which is casted to an
+    /// object type, if [`report_excess_properties_in_type_casts`] is enabled.
+    ///
+    /// ```ts
+    /// ({ a: 1, b: 2 } as { a: number });
+    /// ```
+    ///
+    /// [`report_excess_properties_in_type_casts`]: stc_ts_env::Rule::report_excess_properties_in_type_casts
+    fn report_excess_properties_in_type_cast(&mut self, span: Span, expr: &RExpr, orig_ty: &Type, casted_ty: &Type) {
+        if !self.rule().report_excess_properties_in_type_casts {
+            return;
+        }
+
+        if !matches!(expr, RExpr::Object(..)) {
+            return;
+        }
+
+        let casted_ty = match self.expand_top_ref(span, Cow::Borrowed(casted_ty), Default::default()) {
+            Ok(ty) => ty,
+            Err(..) => return,
+        };
+        if !matches!(casted_ty.normalize(), Type::TypeLit(..) | Type::Interface(..)) {
+            return;
+        }
+
+        let err = match self.assign_with_opts(
+            &mut Default::default(),
+            AssignOpts {
+                span,
+                ..Default::default()
+            },
+            &casted_ty,
+            orig_ty,
+        ) {
+            Ok(()) => return,
+            Err(err) => err,
+        };
+
+        fn collect(err: Error, buf: &mut Errors) {
+            match err {
+                Error::DebugContext(ctx) => collect(*ctx.inner, buf),
+                Error::Errors { errors, .. }
+                | Error::TupleAssignError { errors, .. }
+                | Error::AssignFailed { cause: errors, .. }
+                | Error::SimpleAssignFailedWithCause { cause: errors, .. } => {
+                    for err in errors {
+                        collect(err, buf);
+                    }
+                }
+                Error::SimpleAssignFailed { cause: Some(cause), .. } => collect(*cause, buf),
+                Error::UnknownPropertyInObjectLiteralAssignment { .. } => buf.push(err),
+                _ => {}
+            }
+        }
+
+        let mut errors = Errors::default();
+        collect(err, &mut errors);
+        self.storage.report_all(errors);
+    }
+
+    /// Returns the reason the type assertion at `span` was allowed.
+    ///
+    /// Returns [None] if there's no type assertion at `span` or the type
+    /// assertion is invalid.
+    pub fn cast_kind(&self, span: Span) -> Option<CastKind> {
+        self.data.cast_kinds.get(&span).copied()
+    }
+
     /// ```ts
     /// var unionTuple3: [number, string | number] = [10, "foo"];
     /// var unionTuple4 = <[number, number]>unionTuple3;
@@ -86,7 +203,111 @@ impl Analyzer<'_, '_> {
     /// ```
     ///
     /// results in error.
-    fn validate_type_cast(&mut self, span: Span, orig_ty: Type, casted_ty: Type) -> VResult {
+    fn validate_type_cast(&mut self, span: Span, expr: &RExpr, orig_ty: Type, casted_ty: Type) -> VResult {
+        if self.is_unresolved_cast_target(&casted_ty) {
+            return Ok(casted_ty);
+        }
+
+        let orig_ty = self.expand_cast_source(span, orig_ty)?;
+        let casted_ty = self.prepare_cast_target(casted_ty);
+        let (resolved_casted_ty, is_evaluated_conditional) = self.resolve_cast_target(span, &casted_ty);
+
+        let res = self.validate_type_cast_inner(span, &orig_ty, &resolved_casted_ty);
+        if let Ok(kind) = &res {
+            self.data.cast_kinds.insert(span, *kind);
+        }
+        let res = match res {
+            Err(err) if matches!(err.actual(), Error::InvalidTupleCast { .. }) => {
+                Err(excess_array_elements_error(span, expr, &resolved_casted_ty).unwrap_or(err))
+            }
+            _ => res,
+        };
+        let res = self.adjust_type_cast_error_severity(res);
+        res.report(&mut self.storage);
+
+        // `x as NonNullable<typeof x>` evaluates to the filtered type.
+        if is_evaluated_conditional {
+            return Ok(resolved_casted_ty);
+        }
+
+        // `value as T[keyof T]` evaluates to the union of property types if `T` is
+        // known, so that members of the result can be accessed.
+        if casted_ty.is_indexed_access_type() {
+            if let Ok(resolved) = self.normalize(Some(span), Cow::Borrowed(&casted_ty), Default::default()) {
+                if !resolved.is_indexed_access_type() {
+                    return Ok(resolved.into_owned().freezed());
+                }
+            }
+        }
+
+        Ok(casted_ty)
+    }
+
+    /// Validates many type assertions at once.
+    ///
+    /// Each item is `(span, orig_ty, casted_ty)`. Targets are resolved only
+    /// once even if they appear multiple times, so this is faster than
+    /// validating each type assertion separately.
+    ///
+    /// Nothing is reported. Use [Analyzer::validate_casts_with_notes] to get
+    /// the notes emitted while validating each type assertion.
+    pub fn validate_casts(&mut self, casts: &[(Span, Type, Type)]) -> Vec<VResult<()>> {
+        self.validate_casts_with_notes(casts).into_iter().map(|(res, _)| res).collect()
+    }
+
+    /// Same as [Analyzer::validate_casts], but each result is returned with the
+    /// notes emitted while validating the type assertion.
+    pub fn validate_casts_with_notes(&mut self, casts: &[(Span, Type, Type)]) -> Vec<(VResult<()>, Errors)> {
+        // Notes are collected per type assertion, so errors reported before are
+        // put back at the end.
+        let reported = self.storage.take_errors();
+
+        let mut targets = CastTargetCache::default();
+        let mut results = Vec::with_capacity(casts.len());
+
+        for (span, orig_ty, casted_ty) in casts {
+            let span = *span;
+            if self.is_unresolved_cast_target(casted_ty) {
+                results.push((Ok(()), Errors::default()));
+                continue;
+            }
+
+            let resolved = targets.get_or_insert_with(casted_ty, |casted_ty| {
+                let prepared = self.prepare_cast_target(casted_ty.clone());
+                self.resolve_cast_target(span, &prepared).0
+            });
+
+            let res = self.expand_cast_source(span, orig_ty.clone()).and_then(|orig_ty| {
+                let kind = self.validate_type_cast_inner(span, &orig_ty, &resolved)?;
+                self.data.cast_kinds.insert(span, kind);
+                Ok(())
+            });
+            let res = self.adjust_type_cast_error_severity(res);
+
+            results.push((res, self.storage.take_errors()));
+        }
+
+        self.storage.report_all(reported);
+
+        results
+    }
+
+    /// Converts non-overlapping type casts to warnings if
+    /// `non_overlapping_type_casts_as_warnings` is set.
+    fn adjust_type_cast_error_severity<T>(&self, res: VResult<T>) -> VResult<T> {
+        if !self.rule().non_overlapping_type_casts_as_warnings {
+            return res;
+        }
+
+        res.map_err(|err| {
+            err.convert_all(|err| match err {
+                Error::NonOverlappingTypeCast { span } => Error::NonOverlappingTypeCastWarning { span },
+                _ => err,
+            })
+        })
+    }
+
+    fn expand_cast_source(&mut self, span: Span, orig_ty: Type) -> VResult<Type> {
         let mut orig_ty = self.expand(
             span,
             orig_ty,
@@ -98,6 +319,10 @@ impl Analyzer<'_, '_> {
         )?;
         orig_ty.make_clone_cheap();
 
+        Ok(orig_ty)
+    }
+
+    fn prepare_cast_target(&mut self, casted_ty: Type) -> Type {
         let mut casted_ty = make_instance_type(self.ctx.module_id, casted_ty);
         self.prevent_inference_while_simplifying(&mut casted_ty);
         casted_ty = self.simplify(casted_ty);
@@ -105,16 +330,107 @@ impl Analyzer<'_, '_> {
         self.prevent_expansion(&mut casted_ty);
         casted_ty.make_clone_cheap();
 
-        self.validate_type_cast_inner(span, &orig_ty, &casted_ty).report(&mut self.storage);
+        casted_ty
+    }
 
-        Ok(casted_ty)
+    /// Resolves the target of a type assertion for the overlap check.
+    ///
+    /// The second element of the returned tuple is `true` if the target was a
+    /// conditional type which is evaluated.
+    fn resolve_cast_target(&mut self, span: Span, casted_ty: &Type) -> (Type, bool) {
+        // typeof import("./foo")
+        if let Type::Query(QueryType {
+            expr: box QueryExpr::Import(import),
+            ..
+        }) = casted_ty.normalize()
+        {
+            if let Some(ty) = self.resolve_import_query(span, import) {
+                return (ty.freezed(), false);
+            }
+        }
+
+        // `Awaited<Promise<Promise<number>>>` is resolved to `number`.
+        if let Some(arg) = unwrap_ref_with_single_arg(casted_ty, "Awaited") {
+            if let Ok(awaited) = self.get_awaited_type(span, Cow::Borrowed(arg)) {
+                return (awaited.into_owned().freezed(), false);
+            }
+        }
+
+        // Type arguments omitted in favor of defaults are filled before expansion, so
+        // that `x as Box<number>` is checked against `Box<number, string>`.
+        let filled_casted_ty = match casted_ty.normalize() {
+            Type::Ref(r) => self
+                .fill_default_type_args(r)
+                .ok()
+                .flatten()
+                .map(Type::Ref)
+                .map(Cow::Owned)
+                .unwrap_or_else(|| Cow::Borrowed(casted_ty)),
+            _ => Cow::Borrowed(casted_ty),
+        };
+
+        // Utility types like `ReturnType<T>` or `InstanceType<T>` should be resolved
+        // before checking for overlap, but we return the original type.
+        let mut resolved_casted_ty = self
+            .expand_top_ref(span, filled_casted_ty, Default::default())
+            .map(|ty| ty.into_owned())
+            .unwrap_or_else(|_| casted_ty.clone())
+            .freezed();
+
+        // `typeof Foo` is resolved so that constructors are compared directly.
+        if resolved_casted_ty.is_query() {
+            if let Ok(resolved) = self.normalize(Some(span), Cow::Borrowed(&resolved_casted_ty), Default::default()) {
+                if resolved.normalize().is_class_def() {
+                    resolved_casted_ty = resolved.into_owned().freezed();
+                }
+            }
+        }
+
+        // `Exclude<A, B>`, `Extract<A, B>` and `NonNullable<T>` expand to conditional
+        // types, so we evaluate them to get the filtered union.
+        let mut is_evaluated_conditional = false;
+        if resolved_casted_ty.is_conditional() {
+            if let Ok(evaluated) = self.normalize(Some(span), Cow::Borrowed(&resolved_casted_ty), Default::default()) {
+                if !evaluated.is_conditional() {
+                    resolved_casted_ty = evaluated.into_owned().freezed();
+                    is_evaluated_conditional = true;
+                }
+            }
+        }
+
+        (resolved_casted_ty, is_evaluated_conditional)
     }
 
-    fn validate_type_cast_inner(&mut self, span: Span, orig: &Type, casted: &Type) -> VResult<()> {
+    fn validate_type_cast_inner(&mut self, span: Span, orig: &Type, casted: &Type) -> VResult<CastKind> {
+        if orig.type_eq(casted) || is_same_boolean(orig, casted) {
+            return Ok(CastKind::Identity);
+        }
+
+        if orig.is_any() || casted.is_any() {
+            return Ok(CastKind::Any);
+        }
+
+        // `Args` is bound to the tuple, so it's allowed if the tuple overlaps with the
+        // constraint.
+        //
+        // function f<Args extends unknown[]>() {
+        //     [1, "x"] as [...Args];
+        // }
+        if let Some(param) = generic_rest_param(casted) {
+            let overlaps = match param.constraint.as_deref().map(|c| c.normalize()) {
+                None => true,
+                Some(Type::Array(Array { elem_type, .. })) if elem_type.is_any() || elem_type.is_unknown() => true,
+                Some(constraint) => self.has_overlap(span, orig, constraint, Default::default())?,
+            };
+            if overlaps {
+                return Ok(CastKind::Overlap);
+            }
+        }
+
         // I don't know why this is valid, but `stringLiteralsWithTypeAssertions01.ts`
         // has some tests for this.
         if is_str_or_union(&orig) && casted.is_str() {
-            return Ok(());
+            return Ok(CastKind::Supertype);
         }
 
         match orig.normalize() {
@@ -122,10 +438,77 @@ impl Analyzer<'_, '_> {
                 let castable = rt.types.iter().any(|v| casted.type_eq(v));
 
                 if castable {
-                    return Ok(());
+                    return Ok(CastKind::Subtype);
+                }
+            }
+
+            // (A & B) as A
+            Type::Intersection(ref it) => {
+                for ty in &it.types {
+                    if casted.type_eq(ty) || self.extends(span, ty, casted, Default::default()) == Some(true) {
+                        return Ok(CastKind::Supertype);
+                    }
+                }
+            }
+
+            _ => {}
+        }
+
+        // `null` and `undefined` in the target don't affect the result for non-nullable
+        // values, so we check remaining members.
+        //
+        // { a: 1 } as { a: number } | null
+        if !orig.is_null_or_undefined() {
+            if let Type::Union(u) = casted.normalize() {
+                if u.types.iter().any(|ty| ty.is_null_or_undefined()) && !u.types.iter().all(|ty| ty.is_null_or_undefined()) {
+                    let casted = Type::new_union(
+                        u.span,
+                        u.types.iter().filter(|ty| !ty.is_null_or_undefined()).cloned().collect::<Vec<_>>(),
+                    )
+                    .freezed();
+
+                    return self.validate_type_cast_inner(span, orig, &casted);
                 }
             }
+        }
 
+        // Members of a union are tried before checking the length of tuples.
+        //
+        // [1, "x"] as [number, string] | [string, number]
+        if let Type::Union(u) = casted.normalize() {
+            if orig.is_tuple() && u.types.iter().any(|ty| ty.is_tuple()) {
+                for ty in &u.types {
+                    if let Ok(kind) = self.validate_type_cast_inner(span, orig, ty) {
+                        return Ok(kind);
+                    }
+                }
+            }
+        }
+
+        // Elements of readonly tuples are compared as if they were mutable.
+        //
+        // [1, 2] as readonly [number, number]
+        match (readonly_tuple(orig), readonly_tuple(casted)) {
+            (Some(orig), Some(casted)) => return self.validate_type_cast_inner(span, orig, casted),
+            (Some(orig), None) if casted.is_tuple() => {
+                let kind = self.validate_type_cast_inner(span, orig, casted)?;
+                if self.rule().note_readonly_removal_in_type_casts {
+                    self.storage.report(Error::CastRemovesReadonly { span });
+                }
+
+                return Ok(match kind {
+                    CastKind::Identity => CastKind::Subtype,
+                    _ => kind,
+                });
+            }
+            (None, Some(casted)) if orig.is_tuple() => {
+                let kind = self.validate_type_cast_inner(span, orig, casted)?;
+
+                return Ok(match kind {
+                    CastKind::Identity => CastKind::Supertype,
+                    _ => kind,
+                });
+            }
             _ => {}
         }
 
@@ -161,7 +544,11 @@ impl Analyzer<'_, '_> {
                         }
 
                         if all_castable {
-                            return Ok(());
+                            if self.rule().note_tuple_label_changes_in_type_casts {
+                                self.note_tuple_label_changes(span, &rt.elems, &lt.elems);
+                            }
+
+                            return Ok(CastKind::Overlap);
                         }
                     }
 
@@ -174,7 +561,30 @@ impl Analyzer<'_, '_> {
                 match orig.normalize() {
                     Type::Tuple(ref rt) => {
                         if rt.elems[0].ty.type_eq(&lt.elem_type) {
-                            return Ok(());
+                            return Ok(CastKind::Overlap);
+                        }
+                    }
+
+                    Type::Array(ref rt) => {
+                        // `any[]` overlaps with all arrays.
+                        if lt.elem_type.is_any() || rt.elem_type.is_any() {
+                            return Ok(CastKind::Any);
+                        }
+
+                        // `never[]`, like the type of `[]`, is assignable to all arrays.
+                        if rt.elem_type.is_never() {
+                            return Ok(CastKind::Supertype);
+                        }
+                        if lt.elem_type.is_never() {
+                            return Ok(CastKind::Subtype);
+                        }
+
+                        // `unknown[]` is a supertype of all arrays, which is handled below.
+                        if !lt.elem_type.is_unknown()
+                            && !rt.elem_type.is_unknown()
+                            && self.has_overlap(span, &rt.elem_type, &lt.elem_type, Default::default())?
+                        {
+                            return Ok(CastKind::Overlap);
                         }
                     }
 
@@ -214,119 +624,609 @@ impl Analyzer<'_, '_> {
         //
         // We can cast P to C
         if let Some(true) = self.extends(span, orig, casted, Default::default()) {
-            return Ok(());
+            return Ok(CastKind::Supertype);
         }
         if let Some(true) = self.extends(span, casted, orig, Default::default()) {
-            return Ok(());
+            return Ok(CastKind::Subtype);
         }
 
-        self.castable(span, &orig, &casted, Default::default())
+        let res = self
+            .castable(span, &orig, &casted, Default::default())
             .and_then(|castable| {
                 if castable {
-                    Ok(())
+                    Ok(CastKind::Overlap)
                 } else {
                     Err(Error::NonOverlappingTypeCast { span })
                 }
             })
-            .convert_err(|err| Error::NonOverlappingTypeCast { span })
+            .convert_err(|err| Error::NonOverlappingTypeCast { span });
+
+        // 0 as 0n
+        if res.is_err() && self.rule().note_number_bigint_casts && is_number_bigint_lit_pair(orig, casted) {
+            self.storage.report(Error::NumberBigIntCast { span });
+        }
+
+        res
     }
 
     pub(crate) fn has_overlap(&mut self, span: Span, l: &Type, r: &Type, opts: CastableOpts) -> VResult<bool> {
         let l = l.normalize();
         let r = r.normalize();
 
-        if l.type_eq(r) {
+        if l.type_eq(r) || is_same_boolean(l, r) {
             return Ok(true);
         }
 
+        // An intersection of object types overlaps with a type only if all members
+        // overlap with it.
+        //
+        // declare var v: { kind: "a" } & { value: number };
+        // v as { kind: "b" }; // error
+        for (intersection, other) in [(l, r), (r, l)] {
+            if let Type::Intersection(intersection) = intersection {
+                if intersection
+                    .types
+                    .iter()
+                    .all(|ty| is_object_like(ty) || matches!(ty.normalize(), Type::Ref(..) | Type::Class(..)))
+                {
+                    for ty in &intersection.types {
+                        if !self.has_overlap(span, ty, other, opts)? {
+                            return Ok(false);
+                        }
+                    }
+
+                    return Ok(true);
+                }
+            }
+        }
+
         Ok(self.castable(span, l, r, opts)? || self.castable(span, r, l, opts)?)
     }
 
+    /// Checks if the types of properties of `concrete` overlap with the type of
+    /// the string index signature of `indexed`.
+    ///
+    /// Returns [None] if `indexed` does not have a string index signature or
+    /// `concrete` has an index signature.
+    fn index_signature_overlaps_properties(
+        &mut self,
+        span: Span,
+        indexed: &TypeLit,
+        concrete: &TypeLit,
+        opts: CastableOpts,
+    ) -> VResult<Option<bool>> {
+        if concrete.members.iter().any(|m| matches!(m, TypeElement::Index(..))) {
+            return Ok(None);
+        }
+
+        let index_ty = indexed.members.iter().find_map(|m| match m {
+            TypeElement::Index(i) if i.params.len() == 1 && i.params[0].ty.is_kwd(TsKeywordTypeKind::TsStringKeyword) => {
+                i.type_ann.as_deref()
+            }
+            _ => None,
+        });
+        let index_ty = match index_ty {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        for m in &concrete.members {
+            if let TypeElement::Property(p) = m {
+                if let Some(prop_ty) = &p.type_ann {
+                    if !self.has_overlap(span, index_ty, prop_ty, opts)? {
+                        return Ok(Some(false));
+                    }
+                }
+            }
+        }
+
+        Ok(Some(true))
+    }
+
+    /// Returns `true` if a function type overlaps with one of call signatures
+    /// or construct signatures of `to`.
+    ///
+    /// ```ts
+    /// declare var f: () => Foo;
+    ///
+    /// f as { new (): Foo };
+    /// ```
+    ///
+    /// `this` parameters are not counted as parameters. They are compared
+    /// with each other, or with `to` if only `from` has one.
+    fn fn_overlaps_signatures(&mut self, span: Span, from: &Function, to: &Type) -> VResult<bool> {
+        let enclosing = to;
+        let to = match self.convert_type_to_type_lit(span, Cow::Borrowed(to))? {
+            Some(v) => v,
+            None => return Ok(false),
+        };
+
+        let (from_this, from_params) = split_this_param(&from.params);
+        let from = Type::Function(Function {
+            params: from_params,
+            ..from.clone()
+        })
+        .freezed();
+
+        for m in &to.members {
+            let (type_params, params, ret_ty) = match m {
+                TypeElement::Call(c) => (&c.type_params, &c.params, &c.ret_ty),
+                TypeElement::Constructor(c) => (&c.type_params, &c.params, &c.ret_ty),
+                _ => continue,
+            };
+
+            let (sig_this, params) = split_this_param(params);
+            if let Some(from_this) = from_this {
+                let this_overlaps = match sig_this {
+                    Some(sig_this) => self.has_overlap(span, from_this, sig_this, Default::default())?,
+                    None => self.has_overlap(span, from_this, enclosing, Default::default())?,
+                };
+                if !this_overlaps {
+                    continue;
+                }
+            }
+
+            let sig = Type::Function(Function {
+                span,
+                type_params: type_params.clone(),
+                params,
+                ret_ty: ret_ty.clone().unwrap_or_else(|| box Type::any(span, Default::default())),
+                metadata: Default::default(),
+            })
+            .freezed();
+
+            if self.assign(span, &mut Default::default(), &sig, &from).is_ok()
+                || self.assign(span, &mut Default::default(), &from, &sig).is_ok()
+            {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Returns `true` if `ancestor` is in the heritage chain of `child`.
+    ///
+    /// Parents are resolved in the module which declared the interface
+    /// extending them. Parents which cannot be resolved are ignored, as the
+    /// cast is then validated using the members of the interfaces.
+    fn is_derived_interface(&mut self, child: &Interface, ancestor: &Interface) -> bool {
+        let mut visited = vec![];
+        let ctxt = self.declaring_module_of_interface(child, self.ctx.module_id);
+        let mut queue = child.extends.iter().map(|parent| (ctxt, parent.clone())).collect::<Vec<_>>();
+
+        while let Some((ctxt, parent)) = queue.pop() {
+            for parent in self.find_parent_interfaces(ctxt, &parent) {
+                if parent.name == ancestor.name {
+                    return true;
+                }
+
+                if visited.contains(&parent.name) {
+                    continue;
+                }
+                visited.push(parent.name.clone());
+
+                let ctxt = self.declaring_module_of_interface(&parent, ctxt);
+                queue.extend(parent.extends.into_iter().map(|grand_parent| (ctxt, grand_parent)));
+            }
+        }
+
+        false
+    }
+
+    /// Returns the interfaces named by `parent`, which is in the heritage
+    /// clause of an interface declared in the module `ctxt`.
+    fn find_parent_interfaces(&mut self, ctxt: ModuleId, parent: &TsExpr) -> Vec<Interface> {
+        let types = match &parent.expr {
+            RTsEntityName::Ident(i) => match self.find_type(ctxt, &i.into()) {
+                Ok(Some(types)) => types.map(Cow::into_owned).collect(),
+                _ => vec![],
+            },
+            // Qualified names are resolved only in the current module.
+            RTsEntityName::TsQualifiedName(..) if ctxt == self.ctx.module_id => self
+                .type_of_ts_entity_name(parent.span, ctxt, &parent.expr, parent.type_args.as_deref())
+                .map(|ty| vec![ty])
+                .unwrap_or_default(),
+            RTsEntityName::TsQualifiedName(..) => vec![],
+        };
+
+        types
+            .into_iter()
+            .filter_map(|ty| match ty.normalize() {
+                Type::Interface(i) => Some(i.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the imported module which declared `interface`, or `default` if
+    /// it is not declared by a module imported by the current module.
+    fn declaring_module_of_interface(&self, interface: &Interface, default: ModuleId) -> ModuleId {
+        let declares = |data: &Type| match data.normalize() {
+            Type::Module(data) => data
+                .exports
+                .types
+                .get(interface.name.sym())
+                .into_iter()
+                .chain(data.exports.private_types.get(&interface.name))
+                .flatten()
+                .any(|ty| matches!(ty.normalize(), Type::Interface(i) if i.name == interface.name)),
+            _ => false,
+        };
+
+        self.imports
+            .iter()
+            .find(|((base, _), data)| *base == self.ctx.module_id && declares(data))
+            .map(|((_, dep), _)| *dep)
+            .unwrap_or(default)
+    }
+
+    /// Returns `true` if a `private` or `protected` instance member of `class`
+    /// has the same name as a member of `to`.
+    fn has_non_public_member_in(&mut self, span: Span, class: &ClassDef, to: &Type) -> VResult<bool> {
+        let to = match self.convert_type_to_type_lit(span, Cow::Borrowed(to))? {
+            Some(v) => v,
+            None => return Ok(false),
+        };
+
+        Ok(class.body.iter().any(|member| {
+            let (key, accessibility) = match member {
+                ClassMember::Property(ClassProperty {
+                    key,
+                    accessibility,
+                    is_static: false,
+                    ..
+                })
+                | ClassMember::Method(Method {
+                    key,
+                    accessibility,
+                    is_static: false,
+                    ..
+                }) => (key, accessibility),
+                _ => return false,
+            };
+
+            matches!(accessibility, Some(Accessibility::Private | Accessibility::Protected))
+                && to.members.iter().any(|m| m.key().map_or(false, |k| k.type_eq(key)))
+        }))
+    }
+
+    /// Returns the primitive type all members of the enum are based on. This
+    /// returns [None] for heterogeneous enums and non-enum types.
+    fn enum_base_kind(&mut self, ty: &Type) -> VResult<Option<TsKeywordTypeKind>> {
+        match ty.normalize() {
+            Type::Enum(e) => Ok(enum_kind(e)),
+            Type::EnumVariant(EnumVariant { name: Some(..), .. }) => match self.expand_enum_variant(ty.clone())?.normalize() {
+                Type::Lit(LitType {
+                    lit: RTsLit::Number(..), ..
+                }) => Ok(Some(TsKeywordTypeKind::TsNumberKeyword)),
+                Type::Lit(LitType { lit: RTsLit::Str(..), .. }) => Ok(Some(TsKeywordTypeKind::TsStringKeyword)),
+                _ => Ok(None),
+            },
+            Type::EnumVariant(EnumVariant {
+                ctxt,
+                enum_name,
+                name: None,
+                ..
+            }) => {
+                if let Some(types) = self.find_type(*ctxt, enum_name)? {
+                    for ty in types {
+                        if let Type::Enum(e) = ty.normalize() {
+                            return Ok(enum_kind(e));
+                        }
+                    }
+                }
+
+                Ok(None)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Resolves `typeof import("./foo")` to an object type with the exported
+    /// variables of the module, so that it can be compared structurally.
+    fn resolve_import_query(&mut self, span: Span, import: &ImportType) -> Option<Type> {
+        let (dep, data) = self.get_imported_items(span, &import.arg.value);
+        if dep == self.ctx.module_id {
+            return None;
+        }
+
+        let exports = match data.normalize() {
+            Type::Module(m) => &m.exports,
+            _ => return None,
+        };
+
+        match &import.qualifier {
+            Some(RTsEntityName::Ident(i)) => return exports.vars.get(&i.sym).cloned(),
+            Some(..) => return None,
+            None => {}
+        }
+
+        let mut vars = exports.vars.iter().collect::<Vec<_>>();
+        vars.sort_by(|a, b| a.0.cmp(b.0));
+
+        Some(Type::TypeLit(TypeLit {
+            span,
+            members: vars
+                .into_iter()
+                .map(|(sym, ty)| {
+                    TypeElement::Property(PropertySignature {
+                        span,
+                        accessibility: None,
+                        readonly: true,
+                        key: Key::Normal { span, sym: sym.clone() },
+                        optional: false,
+                        params: Default::default(),
+                        type_ann: Some(box ty.clone()),
+                        type_params: Default::default(),
+                        metadata: Default::default(),
+                        accessor: Default::default(),
+                    })
+                })
+                .collect(),
+            metadata: Default::default(),
+        }))
+    }
+
+    /// Returns `r` with the omitted type arguments replaced by the defaults of
+    /// the declaration, or `None` if there's nothing to fill.
+    ///
+    /// ```ts
+    /// interface Box<T, U = T[]> {}
+    ///
+    /// x as Box<number>; // Box<number, number[]>
+    /// ```
+    fn fill_default_type_args(&mut self, r: &Ref) -> VResult<Option<Ref>> {
+        let name = match &r.type_name {
+            RTsEntityName::Ident(i) => i.into(),
+            _ => return Ok(None),
+        };
+        let types = match self.find_type(r.ctxt, &name)? {
+            Some(types) => types,
+            None => return Ok(None),
+        };
+        let type_params = types.into_iter().find_map(|ty| match ty.normalize() {
+            Type::Interface(Interface { type_params, .. })
+            | Type::Alias(Alias { type_params, .. })
+            | Type::ClassDef(ClassDef { type_params, .. }) => type_params.clone(),
+            Type::Class(c) => c.def.type_params.clone(),
+            _ => None,
+        });
+        let type_params = match type_params {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        let given = r.type_args.as_ref().map(|v| v.params.len()).unwrap_or(0);
+        if given >= type_params.params.len() || type_params.params[given..].iter().any(|p| p.default.is_none()) {
+            return Ok(None);
+        }
+
+        // Defaults may refer to previous type parameters.
+        let mut map = FxHashMap::default();
+        let mut params = vec![];
+        for (idx, param) in type_params.params.iter().enumerate() {
+            let arg = match r.type_args.as_ref().and_then(|v| v.params.get(idx)) {
+                Some(arg) => arg.clone(),
+                None => self.expand_type_params(&map, *param.default.clone().unwrap(), Default::default())?,
+            }
+            .freezed();
+
+            map.insert(param.name.clone(), arg.clone());
+            params.push(arg);
+        }
+
+        Ok(Some(Ref {
+            type_args: Some(box TypeParamInstantiation { span: r.span, params }),
+            ..r.clone()
+        }))
+    }
+
+    /// Returns `true` if the target of a type assertion is a reference to a
+    /// missing type. The missing name is reported while validating the type,
+    /// so the overlap check is skipped to avoid a misleading error.
+    fn is_unresolved_cast_target(&self, casted_ty: &Type) -> bool {
+        match casted_ty.normalize() {
+            Type::Ref(r) => self.is_unresolved_ref(r),
+            _ => false,
+        }
+    }
+
+    fn is_unresolved_ref(&self, r: &Ref) -> bool {
+        match &r.type_name {
+            RTsEntityName::Ident(i) => matches!(self.find_type(r.ctxt, &i.into()), Ok(None)),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if `from` and `to` have the same properties and the types
+    /// of the properties overlap. `readonly` and optional modifiers are ignored
+    /// because they don't affect the runtime representation.
+    ///
+    /// ```ts
+    /// declare var a: Readonly<Foo>;
+    ///
+    /// a as Foo;
+    /// a as Partial<Foo>;
+    /// ```
+    fn overlaps_ignoring_modifiers(&mut self, span: Span, from: &Type, to: &Type, opts: CastableOpts) -> VResult<bool> {
+        let from = match self.convert_type_to_type_lit(span, Cow::Borrowed(from))? {
+            Some(v) => v.into_owned(),
+            None => return Ok(false),
+        };
+        let to = match self.convert_type_to_type_lit(span, Cow::Borrowed(to))? {
+            Some(v) => v.into_owned(),
+            None => return Ok(false),
+        };
+
+        if from.members.len() != to.members.len() {
+            return Ok(false);
+        }
+
+        for from_member in &from.members {
+            let from_prop = match from_member {
+                TypeElement::Property(p) => p,
+                _ => return Ok(false),
+            };
+            let to_prop = to.members.iter().find_map(|m| match m {
+                TypeElement::Property(p) if p.key.type_eq(&from_prop.key) => Some(p),
+                _ => None,
+            });
+            let to_prop = match to_prop {
+                Some(v) => v,
+                None => return Ok(false),
+            };
+
+            if let (Some(from_ty), Some(to_ty)) = (&from_prop.type_ann, &to_prop.type_ann) {
+                if !self.has_overlap(span, from_ty, to_ty, opts)? {
+                    return Ok(false);
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
     /// # Parameters
     ///
     /// - `l`: from
     /// - `r`: to
 
     pub(crate) fn castable(&mut self, span: Span, from: &Type, to: &Type, opts: CastableOpts) -> VResult<bool> {
-        let from = from.normalize();
-        let to = to.normalize();
+        // Results should not depend on whether an alias is already resolved.
+        let from = skip_alias(from);
+        let to = skip_alias(to);
+
+        // `{}` matches everything except `null` and `undefined`.
+        match to {
+            Type::TypeLit(TypeLit { members, .. }) if members.is_empty() => {
+                if !from.is_null_or_undefined() {
+                    return Ok(true);
+                }
+                if self.rule().strict_null_checks {
+                    return Ok(false);
+                }
+            }
+            _ => {}
+        }
 
         // Overlaps with all types.
         if from.is_any() || from.is_kwd(TsKeywordTypeKind::TsNullKeyword) || from.is_kwd(TsKeywordTypeKind::TsUndefinedKeyword) {
             return Ok(true);
         }
 
-        if from.type_eq(to) {
+        if from.type_eq(to) || is_same_boolean(from, to) {
             return Ok(true);
         }
 
+        if let Some(v) = literal_overlaps_keyword(from, to) {
+            return Ok(v);
+        }
+
         match (from, to) {
+            (Type::Function(..), Type::Interface(Interface { name, .. }))
+            | (Type::Interface(Interface { name, .. }), Type::Function(..))
+                if name == "Function" =>
+            {
+                return Ok(true)
+            }
+
+            // `a${string}` overlaps with `string` and string literals matching it.
             (
-                Type::Lit(LitType {
-                    lit: RTsLit::Number(..), ..
-                }),
-                Type::Keyword(KeywordType {
-                    kind: TsKeywordTypeKind::TsNumberKeyword,
-                    ..
-                }),
-            ) => return Ok(true),
-            (
-                Type::Lit(LitType { lit: RTsLit::Str(..), .. }),
+                Type::Tpl(..),
                 Type::Keyword(KeywordType {
                     kind: TsKeywordTypeKind::TsStringKeyword,
                     ..
                 }),
-            ) => return Ok(true),
-            (
-                Type::Lit(LitType { lit: RTsLit::Bool(..), .. }),
-                Type::Keyword(KeywordType {
-                    kind: TsKeywordTypeKind::TsBooleanKeyword,
-                    ..
-                }),
-            ) => return Ok(true),
-            (
-                Type::Lit(LitType {
-                    lit: RTsLit::BigInt(..), ..
-                }),
+            )
+            | (
                 Type::Keyword(KeywordType {
-                    kind: TsKeywordTypeKind::TsBigIntKeyword,
+                    kind: TsKeywordTypeKind::TsStringKeyword,
                     ..
                 }),
+                Type::Tpl(..),
             ) => return Ok(true),
-            (
-                Type::Lit(LitType {
-                    lit: RTsLit::Number(..), ..
-                }),
-                Type::Lit(LitType {
-                    lit: RTsLit::Number(..), ..
-                }),
-            )
-            | (Type::Lit(LitType { lit: RTsLit::Str(..), .. }), Type::Lit(LitType { lit: RTsLit::Str(..), .. }))
-            | (
-                Type::Lit(LitType {
-                    lit: RTsLit::BigInt(..), ..
-                }),
-                Type::Lit(LitType {
-                    lit: RTsLit::BigInt(..), ..
-                }),
-            ) => return Ok(false),
-
-            (Type::Function(..), Type::Interface(Interface { name, .. })) if name == "Function" => return Ok(true),
+            (Type::Tpl(tpl), lit @ Type::Lit(LitType { lit: RTsLit::Str(..), .. }))
+            | (lit @ Type::Lit(LitType { lit: RTsLit::Str(..), .. }), Type::Tpl(tpl)) => {
+                return Ok(self
+                    .assign_to_tpl(
+                        tpl,
+                        lit,
+                        AssignOpts {
+                            span,
+                            ..Default::default()
+                        },
+                    )
+                    .is_ok())
+            }
             _ => {}
         }
 
+        // Fast path for `"x" as ("a" | "b")`.
+        if let (Type::Lit(..), Type::Union(to)) = (from, to) {
+            if to.types.iter().all(|ty| ty.normalize().is_lit()) {
+                return Ok(to.types.iter().any(|ty| ty.type_eq(from)));
+            }
+        }
+
+        // `Array<T>` and `T[]` are the same type.
+        if let (Some(from_elem), Some(to_elem)) = (array_elem_type(from), array_elem_type(to)) {
+            if from_elem.type_eq(to_elem) {
+                return Ok(true);
+            }
+
+            // `never[]` overlaps with all arrays.
+            if from_elem.is_never() || to_elem.is_never() {
+                return Ok(true);
+            }
+        }
+
         // TODO(kdy1): More check
         if from.is_fn_type() && to.is_fn_type() {
             return Ok(false);
         }
 
+        if let Type::Function(f) = from {
+            if (to.is_interface() || to.is_type_lit()) && self.fn_overlaps_signatures(span, f, to)? {
+                return Ok(true);
+            }
+        }
+
+        // Built-in types like `Map` may not exist depending on `lib`. The missing name
+        // is reported while validating the type, so we don't report an error here.
+        if let (Type::Ref(r), _) | (_, Type::Ref(r)) = (from, to) {
+            if self.is_unresolved_ref(r) {
+                return Ok(true);
+            }
+        }
+
         match (from, to) {
+            // A reference which cannot be expanded is reported while validating the type, so
+            // we treat it as overlapping with all types.
             (Type::Ref(_), _) => {
-                let from = self.expand_top_ref(span, Cow::Borrowed(from), Default::default())?.freezed();
+                let from = match self
+                    .expand_top_ref(span, Cow::Borrowed(from), Default::default())
+                    .context("tried to expand the source type of a type cast")
+                {
+                    Ok(from) => from.freezed(),
+                    Err(err) => {
+                        debug!("castable: {:?}", err);
+                        return Ok(true);
+                    }
+                };
                 return self.castable(span, &from, to, opts);
             }
             (_, Type::Ref(_)) => {
-                let to = self.expand_top_ref(span, Cow::Borrowed(to), Default::default())?.freezed();
+                let to = match self
+                    .expand_top_ref(span, Cow::Borrowed(to), Default::default())
+                    .context("tried to expand the target type of a type cast")
+                {
+                    Ok(to) => to.freezed(),
+                    Err(err) => {
+                        debug!("castable: {:?}", err);
+                        return Ok(true);
+                    }
+                };
                 return self.castable(span, from, &to, opts);
             }
 
@@ -354,11 +1254,56 @@ impl Analyzer<'_, '_> {
                         }
                     }
                 }
+
+                // Record<string, unknown> as { a: number }
+                if let Some(v) = self.index_signature_overlaps_properties(span, lt, rt, opts)? {
+                    return Ok(v);
+                }
+                // { a: number } as Record<string, unknown>
+                if let Some(v) = self.index_signature_overlaps_properties(span, rt, lt, opts)? {
+                    return Ok(v);
+                }
+            }
+
+            // interface Dict {
+            //     [key: string]: number;
+            // }
+            //
+            // ({ a: 1 }) as Dict;
+            // dict as { a: number };
+            (Type::Interface(..), Type::TypeLit(concrete)) | (Type::TypeLit(concrete), Type::Interface(..)) => {
+                let indexed = if from.is_interface() { from } else { to };
+                if let Some(indexed) = self.convert_type_to_type_lit(span, Cow::Borrowed(indexed))? {
+                    if let Some(v) = self.index_signature_overlaps_properties(span, &indexed, concrete, opts)? {
+                        return Ok(v);
+                    }
+                }
             }
 
             _ => {}
         }
 
+        if let (Type::Interface(l), Type::Interface(r)) = (from, to) {
+            // interface P {}
+            // interface C extends P {}
+            // interface D extends P {}
+            //
+            // We can cast C to P and P to C.
+            if self.is_derived_interface(l, r) || self.is_derived_interface(r, l) {
+                return Ok(true);
+            }
+
+            // Unrelated interfaces overlap only if one of them does not have a required
+            // member missing in the other.
+            let l = self.convert_type_to_type_lit(span, Cow::Borrowed(from))?;
+            let r = self.convert_type_to_type_lit(span, Cow::Borrowed(to))?;
+            if let (Some(l), Some(r)) = (l, r) {
+                if has_missing_required_member(&l, &r) && has_missing_required_member(&r, &l) {
+                    return Ok(false);
+                }
+            }
+        }
+
         // TODO(kdy1): This is wrong
         if from.is_type_lit() && to.is_type_lit() {
             return Ok(true);
@@ -377,6 +1322,14 @@ impl Analyzer<'_, '_> {
             _ => {}
         }
 
+        // type UserId = number & { __brand: "user" };
+        // type PostId = number & { __brand: "post" };
+        //
+        // userId as PostId; // error
+        if has_conflicting_brands(from, to) {
+            return Ok(false);
+        }
+
         match to {
             Type::Union(to) => {
                 for to in &to.types {
@@ -400,14 +1353,82 @@ impl Analyzer<'_, '_> {
             _ => {}
         }
 
+        // Members of enums are compared with literals by their values.
+        //
+        // enum Color { Red = "red" }
+        //
+        // Color.Red as "red";
+        match (from, to) {
+            (Type::EnumVariant(EnumVariant { name: Some(..), .. }), Type::Lit(..))
+            | (Type::Lit(..), Type::EnumVariant(EnumVariant { name: Some(..), .. })) => {
+                let from = self.expand_enum_variant(from.clone())?;
+                let to = self.expand_enum_variant(to.clone())?;
+
+                if from.is_lit() && to.is_lit() {
+                    return Ok(from.type_eq(&to));
+                }
+            }
+            _ => {}
+        }
+
+        // enum E { A = 1 }
+        //
+        // E.A as number;
+        if let Some(kind) = self.enum_base_kind(from)? {
+            if to.is_kwd(kind) {
+                return Ok(true);
+            }
+        }
+        // `number as E` is allowed, but `string as StringEnum` is allowed only
+        // because `StringEnum as string` is.
+        if from.is_kwd(TsKeywordTypeKind::TsNumberKeyword) && self.enum_base_kind(to)? == Some(TsKeywordTypeKind::TsNumberKeyword) {
+            return Ok(true);
+        }
+
         if from.is_num() {
             if self.can_be_casted_to_number_in_rhs(span, &to) {
                 return Ok(true);
             }
         }
 
-        if from.is_class() && (to.is_interface() || to.is_type_lit()) {
-            return Ok(false);
+        // Constructors overlap if one is assignable to the other, which compares
+        // construct signatures and static members.
+        //
+        // typeof A as typeof B
+        if let (Type::ClassDef(..), Type::ClassDef(..)) = (from, to) {
+            let opts = AssignOpts {
+                span,
+                for_castablity: true,
+                ..Default::default()
+            };
+
+            return Ok(self.assign_with_opts(&mut Default::default(), opts, from, to).is_ok()
+                || self.assign_with_opts(&mut Default::default(), opts, to, from).is_ok());
+        }
+
+        // class Foo { a = 1 }
+        // interface Bar { a: number }
+        //
+        // Foo as Bar
+        if let Type::Class(from_class) = from {
+            if to.is_interface() || to.is_type_lit() {
+                if self.has_non_public_member_in(span, &from_class.def, to)? {
+                    return Ok(false);
+                }
+
+                return Ok(self
+                    .assign_with_opts(
+                        &mut Default::default(),
+                        AssignOpts {
+                            span,
+                            for_castablity: true,
+                            ..Default::default()
+                        },
+                        to,
+                        from,
+                    )
+                    .is_ok());
+            }
         }
 
         // class A {}
@@ -430,6 +1451,306 @@ impl Analyzer<'_, '_> {
             return Ok(true);
         }
 
+        // Readonly<Foo> as Foo
+        // Partial<Foo> as Foo
+        if is_object_like(from) && is_object_like(to) && self.overlaps_ignoring_modifiers(span, from, to, opts)? {
+            return Ok(true);
+        }
+
         Ok(false)
     }
 }
+
+/// Returns `true` if one of the types is a number literal and the other is a
+/// bigint literal.
+fn is_number_bigint_lit_pair(l: &Type, r: &Type) -> bool {
+    (l.is_num_lit() && r.is_bigint_lit()) || (l.is_bigint_lit() && r.is_num_lit())
+}
+
+fn is_object_like(ty: &Type) -> bool {
+    matches!(ty.normalize(), Type::TypeLit(..) | Type::Interface(..) | Type::Mapped(..))
+}
+
+fn enum_kind(e: &Enum) -> Option<TsKeywordTypeKind> {
+    match (e.has_num, e.has_str) {
+        (_, false) => Some(TsKeywordTypeKind::TsNumberKeyword),
+        (false, true) => Some(TsKeywordTypeKind::TsStringKeyword),
+        (true, true) => None,
+    }
+}
+
+/// Splits the type of the `this` parameter from other parameters.
+fn split_this_param(params: &[FnParam]) -> (Option<&Type>, Vec<FnParam>) {
+    let mut this = None;
+    let mut rest = Vec::with_capacity(params.len());
+
+    for p in params {
+        match &p.pat {
+            RPat::Ident(RBindingIdent {
+                id: RIdent { sym: js_word!("this"), .. },
+                ..
+            }) => this = Some(&*p.ty),
+            _ => rest.push(p.clone()),
+        }
+    }
+
+    (this, rest)
+}
+
+/// Returns the aliased type of non-generic type aliases.
+fn skip_alias(ty: &Type) -> &Type {
+    match ty.normalize() {
+        Type::Alias(Alias { type_params: None, ty, .. }) => skip_alias(ty),
+        ty => ty,
+    }
+}
+
+/// Checks if primitive types overlap, without expanding anything.
+///
+/// Returns [None] if one of `from` and `to` is not a keyword or a literal of
+/// `number`, `string`, `boolean` or `bigint`.
+///
+/// - A literal overlaps with the keyword of its kind (`1` and `number`).
+/// - Literals of the same kind overlap only if they are equal.
+/// - Primitives of different kinds never overlap. (`1` and `1n`, `0n` and
+///   `number`, `0` and `bigint`)
+pub(crate) fn literal_overlaps_keyword(from: &Type, to: &Type) -> Option<bool> {
+    let (from_kind, from_is_lit) = primitive_kind(from)?;
+    let (to_kind, to_is_lit) = primitive_kind(to)?;
+
+    if from_kind != to_kind {
+        return Some(false);
+    }
+
+    if from_is_lit && to_is_lit {
+        return Some(from.type_eq(to));
+    }
+
+    Some(true)
+}
+
+/// Returns the keyword kind of a primitive type, and whether it's a literal.
+fn primitive_kind(ty: &Type) -> Option<(TsKeywordTypeKind, bool)> {
+    match ty.normalize() {
+        Type::Lit(LitType { lit, .. }) => match lit {
+            RTsLit::Number(..) => Some((TsKeywordTypeKind::TsNumberKeyword, true)),
+            RTsLit::Str(..) => Some((TsKeywordTypeKind::TsStringKeyword, true)),
+            RTsLit::Bool(..) => Some((TsKeywordTypeKind::TsBooleanKeyword, true)),
+            RTsLit::BigInt(..) => Some((TsKeywordTypeKind::TsBigIntKeyword, true)),
+            _ => None,
+        },
+        Type::Keyword(KeywordType { kind, .. }) => match kind {
+            TsKeywordTypeKind::TsNumberKeyword
+            | TsKeywordTypeKind::TsStringKeyword
+            | TsKeywordTypeKind::TsBooleanKeyword
+            | TsKeywordTypeKind::TsBigIntKeyword => Some((*kind, false)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns `T` of `[...T]`.
+fn generic_rest_param(ty: &Type) -> Option<&TypeParam> {
+    match ty.normalize() {
+        Type::Tuple(Tuple { elems, .. }) if elems.len() == 1 => match elems[0].ty.normalize() {
+            Type::Rest(RestType { ty, .. }) => match ty.normalize() {
+                Type::Param(param) => Some(param),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the tuple wrapped by `readonly`.
+fn readonly_tuple(ty: &Type) -> Option<&Type> {
+    match ty.normalize() {
+        Type::Operator(Operator {
+            op: TsTypeOperatorOp::ReadOnly,
+            ty,
+            ..
+        }) if ty.is_tuple() => Some(ty),
+        _ => None,
+    }
+}
+
+/// Resolved targets of type assertions, used by [Analyzer::validate_casts].
+///
+/// Targets are grouped by their kind (and name for references), and compared
+/// with [TypeEq], so the same target written at different locations is
+/// resolved only once.
+#[derive(Default)]
+pub(super) struct CastTargetCache {
+    targets: FxHashMap<(Discriminant<Type>, Option<JsWord>), Vec<(Type, Type)>>,
+}
+
+impl CastTargetCache {
+    /// Returns the resolved type of `target`. `resolve` is called only if
+    /// `target` is not resolved yet.
+    pub(super) fn get_or_insert_with<F>(&mut self, target: &Type, resolve: F) -> Type
+    where
+        F: FnOnce(&Type) -> Type,
+    {
+        let bucket = self.targets.entry(cast_target_key(target)).or_default();
+        if let Some((_, resolved)) = bucket.iter().find(|(cached, _)| cached.type_eq(target)) {
+            return resolved.clone();
+        }
+
+        let resolved = resolve(target);
+        bucket.push((target.clone(), resolved.clone()));
+        resolved
+    }
+
+    /// Returns the number of cached targets.
+    #[cfg(test)]
+    pub(super) fn num_targets(&self) -> usize {
+        self.targets.values().map(Vec::len).sum()
+    }
+}
+
+fn cast_target_key(target: &Type) -> (Discriminant<Type>, Option<JsWord>) {
+    let target = target.normalize();
+    let name = match target {
+        Type::Ref(Ref {
+            type_name: RTsEntityName::Ident(i),
+            ..
+        }) => Some(i.sym.clone()),
+        Type::Ref(Ref {
+            type_name: RTsEntityName::TsQualifiedName(q),
+            ..
+        }) => Some(q.right.sym.clone()),
+        _ => None,
+    };
+
+    (discriminant(target), name)
+}
+
+/// Creates an error pointing at the elements of an array literal which do not
+/// exist in the target tuple.
+///
+/// ```ts
+/// [1, 2, 3] as [number];
+/// ```
+fn excess_array_elements_error(span: Span, expr: &RExpr, casted: &Type) -> Option<Error> {
+    let arr = match expr {
+        RExpr::Array(arr) => arr,
+        _ => return None,
+    };
+    // Spreads make the number of elements unknown, and holes don't have a span.
+    if arr
+        .elems
+        .iter()
+        .any(|elem| elem.as_ref().map_or(true, |elem| elem.spread.is_some()))
+    {
+        return None;
+    }
+
+    let target = match readonly_tuple(casted).unwrap_or(casted).normalize() {
+        Type::Tuple(target) if !target.elems.iter().any(|el| el.ty.is_rest()) => target,
+        _ => return None,
+    };
+    if arr.elems.len() <= target.elems.len() {
+        return None;
+    }
+
+    Some(Error::TupleCastWithExcessElements {
+        span,
+        excess: arr.elems[target.elems.len()..].iter().map(|elem| elem.span()).collect(),
+        source_len: arr.elems.len(),
+        target_len: target.elems.len(),
+    })
+}
+
+/// Returns the element type of `T[]` or `Array<T>`.
+fn array_elem_type(ty: &Type) -> Option<&Type> {
+    match ty.normalize() {
+        Type::Array(Array { elem_type, .. }) => Some(elem_type),
+        _ => unwrap_ref_with_single_arg(ty, "Array"),
+    }
+}
+
+/// Returns `true` if one type is `boolean` and the other is `true | false`.
+fn is_same_boolean(l: &Type, r: &Type) -> bool {
+    fn is_true_or_false(ty: &Type) -> bool {
+        let u = match ty.normalize() {
+            Type::Union(u) => u,
+            _ => return false,
+        };
+
+        let (mut has_true, mut has_false) = (false, false);
+        for ty in &u.types {
+            match ty.normalize() {
+                Type::Lit(LitType { lit: RTsLit::Bool(b), .. }) => {
+                    if b.value {
+                        has_true = true;
+                    } else {
+                        has_false = true;
+                    }
+                }
+                _ => return false,
+            }
+        }
+
+        has_true && has_false
+    }
+
+    (l.is_kwd(TsKeywordTypeKind::TsBooleanKeyword) && is_true_or_false(r))
+        || (r.is_kwd(TsKeywordTypeKind::TsBooleanKeyword) && is_true_or_false(l))
+}
+
+/// Returns `true` if both types are intersections with a property of the same
+/// name, whose literal types are different. This is how brands of opaque types
+/// are usually declared.
+fn has_conflicting_brands(from: &Type, to: &Type) -> bool {
+    let (from, to) = match (from.normalize(), to.normalize()) {
+        (Type::Intersection(from), Type::Intersection(to)) => (from, to),
+        _ => return false,
+    };
+
+    lit_props(from).any(|(from_key, from_ty)| lit_props(to).any(|(to_key, to_ty)| from_key.type_eq(to_key) && !from_ty.type_eq(to_ty)))
+}
+
+/// Returns properties with literal types declared by type literals in the
+/// intersection.
+fn lit_props(i: &Intersection) -> impl Iterator<Item = (&Key, &Type)> {
+    i.types
+        .iter()
+        .filter_map(|ty| match ty.normalize() {
+            Type::TypeLit(lit) => Some(lit),
+            _ => None,
+        })
+        .flat_map(|lit| lit.members.iter())
+        .filter_map(|member| match member {
+            TypeElement::Property(PropertySignature {
+                key, type_ann: Some(ty), ..
+            }) if ty.is_lit() => Some((key, &**ty)),
+            _ => None,
+        })
+}
+
+/// Returns `true` if `to` has a required property or method which does not
+/// exist in `from`.
+fn has_missing_required_member(from: &TypeLit, to: &TypeLit) -> bool {
+    to.members.iter().any(|to_member| {
+        let is_required = match to_member {
+            TypeElement::Property(p) => !p.optional,
+            TypeElement::Method(m) => !m.optional,
+            _ => false,
+        };
+        if !is_required {
+            return false;
+        }
+
+        let key = match to_member.key() {
+            Some(key) => key,
+            None => return false,
+        };
+
+        from.members.iter().all(|from_member| match from_member.key() {
+            Some(from_key) => !from_key.type_eq(key),
+            None => true,
+        })
+    })
+}
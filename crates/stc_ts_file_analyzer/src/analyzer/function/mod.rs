@@ -99,6 +99,7 @@ impl Analyzer<'_, '_> {
                 };
                 f.params.validate_with(&mut *child.with_ctx(ctx))?
             };
+            child.report_error_for_misplaced_rest_params(&params);
 
             if !child.is_builtin {
                 params = params
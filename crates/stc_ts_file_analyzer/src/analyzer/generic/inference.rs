@@ -96,6 +96,29 @@ impl Analyzer<'_, '_> {
             }
         }
 
+        // `U | ReadonlyArray<U>` from `number | number[]` should infer `number`, so
+        // array members of the argument are inferred using array members of the
+        // parameter and remaining members are inferred to the naked type parameter.
+        let mut naked = param.types.iter().filter(|p| p.is_type_param());
+        if let (Some(naked), None) = (naked.next(), naked.next()) {
+            if param.types.iter().any(|p| is_array_like(p)) {
+                let (arrays, others): (Vec<_>, Vec<_>) = arg.types.iter().partition(|a| is_array_like(a));
+
+                if !arrays.is_empty() {
+                    for p in param.types.iter().filter(|p| !p.is_type_param()) {
+                        for a in &arrays {
+                            self.infer_type(span, inferred, p, a, opts)?;
+                        }
+                    }
+                    for a in others {
+                        self.infer_type(span, inferred, naked, a, opts)?;
+                    }
+
+                    return Ok(());
+                }
+            }
+        }
+
         for p in &param.types {
             self.infer_type(span, inferred, p, arg_ty, opts)?;
         }
@@ -824,3 +847,18 @@ fn should_prevent_generalization(constraint: &Type) -> bool {
         _ => false,
     }
 }
+
+/// Returns `true` for `T[]`, `readonly T[]`, `Array<T>` and `ReadonlyArray<T>`.
+fn is_array_like(ty: &Type) -> bool {
+    match ty.normalize() {
+        Type::Array(..) => true,
+        Type::Operator(Operator {
+            op: TsTypeOperatorOp::ReadOnly,
+            ty,
+            ..
+        }) => is_array_like(ty),
+        _ => unwrap_ref_with_single_arg(ty, "Array")
+            .or_else(|| unwrap_ref_with_single_arg(ty, "ReadonlyArray"))
+            .is_some(),
+    }
+}
@@ -114,6 +114,7 @@ impl Analyzer<'_, '_> {
                                 span: arg.spread.unwrap(),
                                 spread: None,
                                 ty: elem.ty.clone(),
+                                label: elem.label.clone(),
                             }));
                         }
                         _ => {
@@ -211,6 +212,13 @@ impl Analyzer<'_, '_> {
                 _ => {}
             }
 
+            // A type parameter which cannot be inferred defaults to its default type, then
+            // its constraint, then `unknown`.
+            if let Some(default) = &type_param.default {
+                self.insert_inferred(span, &mut inferred, &type_param, Cow::Borrowed(&default), opts)?;
+                continue;
+            }
+
             if type_param.constraint.is_some() && is_literals(&type_param.constraint.as_ref().unwrap()) {
                 self.insert_inferred(
                     span,
@@ -251,11 +259,6 @@ impl Analyzer<'_, '_> {
                 if let Some(default_ty) = inferred.defaults.remove(&type_param.name) {
                     self.insert_inferred(span, &mut inferred, &type_param, Cow::Owned(default_ty), opts)?;
                 } else {
-                    if let Some(default) = &type_param.default {
-                        self.insert_inferred(span, &mut inferred, &type_param, Cow::Borrowed(&default), opts)?;
-                        continue;
-                    }
-
                     if let Some(default_ty) = default_ty {
                         error!("infer: A type parameter {} defaults to {:?}", type_param.name, default_ty);
 
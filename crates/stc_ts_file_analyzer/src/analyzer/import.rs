@@ -2,12 +2,13 @@ use rayon::prelude::*;
 use rnode::{Visit, VisitWith};
 use stc_ts_ast_rnode::{
     RCallExpr, RExportAll, RExpr, RExprOrSuper, RImportDecl, RImportSpecifier, RLit, RModuleItem, RNamedExport, RStr, RTsExternalModuleRef,
+    RTsImportType,
 };
 use stc_ts_errors::Error;
 use stc_ts_file_analyzer_macros::extra_validator;
 use stc_ts_storage::Storage;
 use stc_ts_types::{Id, ModuleId, Type};
-use stc_ts_utils::imports::find_imports_in_comments;
+use stc_ts_utils::imports::{find_imports_in_comments, is_json_import};
 use swc_atoms::{js_word, JsWord};
 use swc_common::{comments::Comments, Span, Spanned};
 
@@ -183,12 +184,47 @@ impl Analyzer<'_, '_> {
     }
 }
 
+impl Analyzer<'_, '_> {
+    /// We don't load JSON modules, so bindings imported from a JSON module
+    /// are typed as `any`.
+    ///
+    /// ```ts
+    /// import data from "./data.json" assert { type: "json" };
+    /// ```
+    fn declare_json_import(&mut self, node: &RImportDecl) {
+        for specifier in &node.specifiers {
+            let (span, local) = match specifier {
+                RImportSpecifier::Named(named) => (named.span, &named.local),
+                RImportSpecifier::Default(default) => (default.span, &default.local),
+                RImportSpecifier::Namespace(ns) => (ns.span, &ns.local),
+            };
+
+            self.declare_var(
+                span,
+                VarKind::Import,
+                local.clone().into(),
+                Some(Type::any(span, Default::default())),
+                None,
+                true,
+                false,
+                false,
+            )
+            .report(&mut self.storage);
+        }
+    }
+}
+
 #[validator]
 impl Analyzer<'_, '_> {
     fn validate(&mut self, node: &RImportDecl) {
         let span = node.span;
         let base = self.ctx.module_id;
 
+        if is_json_import(node.asserts.as_ref()) {
+            self.declare_json_import(node);
+            return Ok(());
+        }
+
         let (dep, data) = self.get_imported_items(span, &node.src.value);
 
         for specifier in &node.specifiers {
@@ -340,6 +376,11 @@ where
     C: Comments,
 {
     fn visit(&mut self, import: &RImportDecl) {
+        // JSON modules are not loaded.
+        if is_json_import(import.asserts.as_ref()) {
+            return;
+        }
+
         let span = import.span();
 
         self.to.push((
@@ -400,3 +441,19 @@ where
         ));
     }
 }
+
+impl<C> Visit<RTsImportType> for ImportFinder<'_, C>
+where
+    C: Comments,
+{
+    /// Extracts `import('foo')` in types.
+    fn visit(&mut self, r: &RTsImportType) {
+        self.to.push((
+            self.cur_ctxt,
+            DepInfo {
+                span: r.span,
+                src: r.arg.value.clone(),
+            },
+        ));
+    }
+}
@@ -17,13 +17,14 @@ use stc_ts_env::{Env, Marks, ModuleConfig, Rule, StableEnv};
 use stc_ts_errors::{debug::debugger::Debugger, Error};
 use stc_ts_storage::{Builtin, Info, Storage};
 use stc_ts_type_cache::TypeCache;
-use stc_ts_types::{Id, IdCtx, ModuleId, ModuleTypeData, Namespace};
+use stc_ts_types::{name::Name, Id, IdCtx, ModuleId, ModuleTypeData, Namespace};
 use stc_ts_utils::StcComments;
 use stc_utils::{cache::Freeze, panic_ctx, AHashMap, AHashSet};
 use swc_atoms::{js_word, JsWord};
 use swc_common::{FileName, SourceMap, Span, Spanned, DUMMY_SP, GLOBALS};
 use swc_ecma_ast::*;
 
+pub use self::expr::CastKind;
 pub(crate) use self::scope::ScopeKind;
 use self::{
     control_flow::{CondFacts, Facts},
@@ -55,6 +56,7 @@ mod class;
 mod control_flow;
 mod convert;
 mod decl_merging;
+mod directive;
 mod enums;
 mod export;
 mod expr;
@@ -291,6 +293,17 @@ struct AnalyzerData {
     cache: TypeCache,
 
     checked_for_async_iterator: bool,
+
+    /// Reasons type assertions are allowed, keyed by the span of the type
+    /// assertion.
+    cast_kinds: FxHashMap<Span, CastKind>,
+
+    /// Names of member expressions aliased by `const` variables, used to
+    /// narrow the object when the variable is used as the discriminant of a
+    /// `switch`.
+    ///
+    /// e.g. `obj.kind` for `const k = obj.kind`
+    aliased_discriminants: FxHashMap<Id, Name>,
 }
 
 #[derive(Debug, Default)]
@@ -806,6 +819,7 @@ impl Analyzer<'_, '_> {
                 m.body.visit_children_with(self);
             } else {
                 self.validate_stmts_and_collect(&items_ref);
+                self.apply_ts_expect_error_directives(m);
             }
 
             Ok(())
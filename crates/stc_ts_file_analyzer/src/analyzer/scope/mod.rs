@@ -11,23 +11,24 @@ use std::{
 use fxhash::{FxHashMap, FxHashSet};
 use iter::once;
 use once_cell::sync::Lazy;
-use rnode::{Fold, FoldWith, VisitMutWith, VisitWith};
+use rnode::{Fold, FoldWith, Visit, VisitMutWith, VisitWith};
 use stc_ts_ast_rnode::{RPat, RTsEntityName, RTsQualifiedName};
 use stc_ts_errors::{
     debug::{dump_type_as_string, print_backtrace},
     DebugExt, Error,
 };
-use stc_ts_generics::ExpandGenericOpts;
+use stc_ts_generics::{type_param::finder::TypeParamUsageFinder, ExpandGenericOpts};
 use stc_ts_type_ops::{expansion::ExpansionPreventer, union_finder::UnionFinder, Fix};
 use stc_ts_types::{
-    name::Name, Class, ClassDef, ClassProperty, Conditional, EnumVariant, FnParam, Id, IndexedAccessType, Intersection, Key, KeywordType,
-    KeywordTypeMetadata, Mapped, ModuleId, Operator, QueryExpr, QueryType, StaticThis, TypeElement, TypeParam, TypeParamInstantiation,
+    name::Name, Class, ClassDef, ClassProperty, Conditional, EnumVariant, FnParam, Id, IndexedAccessType, InferType, Intersection, Key,
+    KeywordType, KeywordTypeMetadata, Mapped, ModuleId, Operator, QueryExpr, QueryType, StaticThis, TypeElement, TypeParam,
+    TypeParamInstantiation,
 };
 use stc_utils::{
     cache::{Freeze, ALLOW_DEEP_CLONE},
     debug_ctx, panic_ctx, stack,
 };
-use swc_atoms::js_word;
+use swc_atoms::{js_word, JsWord};
 use swc_common::{util::move_map::MoveMap, Span, Spanned, SyntaxContext, TypeEq, DUMMY_SP};
 use swc_ecma_ast::*;
 use tracing::{debug, error, info, instrument};
@@ -319,6 +320,18 @@ impl Scope<'_> {
         self.type_params.insert(name, ty);
     }
 
+    /// Returns `true` if `#name` is declared by one of the enclosing classes.
+    pub(crate) fn is_private_name_declared(&self, name: &JsWord) -> bool {
+        if self.kind == ScopeKind::Class && self.class.private_names.contains(name) {
+            return true;
+        }
+
+        match self.parent {
+            Some(parent) => parent.is_private_name_declared(name),
+            None => false,
+        }
+    }
+
     /// Get members of current class.
     pub fn class_members(&self) -> &[(usize, ty::ClassMember)] {
         if let ScopeKind::Class = self.kind {
@@ -343,6 +356,29 @@ impl Scope<'_> {
         }
     }
 
+    /// Returns the kind of the scope `super` refers to, which is either
+    /// [ScopeKind::Class] or [ScopeKind::ObjectLit].
+    ///
+    /// Arrow functions don't have their own `super`, but other functions do.
+    pub fn super_container_kind(&self) -> Option<ScopeKind> {
+        match self.kind {
+            ScopeKind::Fn | ScopeKind::Module => None,
+            ScopeKind::Class => Some(ScopeKind::Class),
+            ScopeKind::Method { .. } | ScopeKind::Constructor => {
+                let mut parent = self.parent;
+                while let Some(scope) = parent {
+                    match scope.kind {
+                        ScopeKind::Class | ScopeKind::ObjectLit => return Some(scope.kind),
+                        _ => parent = scope.parent,
+                    }
+                }
+
+                None
+            }
+            _ => self.parent?.super_container_kind(),
+        }
+    }
+
     pub fn get_super_class(&self) -> Option<&Type> {
         if let ScopeKind::Class = self.kind {
             return self.super_class.as_ref();
@@ -2462,12 +2498,14 @@ impl Expander<'_, '_, '_> {
                                                 _ => {}
                                             };
                                         }
-                                        let type_params = self
-                                            .analyzer
-                                            .infer_ts_infer_types(span, &extends_type, &element.ty, Default::default())
-                                            .ok();
-                                        if let Some(type_params) = type_params {
-                                            ty = self.analyzer.expand_type_params(&type_params, ty, Default::default()).unwrap();
+                                        if v {
+                                            let type_params = self
+                                                .analyzer
+                                                .infer_ts_infer_types(span, &extends_type, &element.ty, Default::default())
+                                                .ok();
+                                            if let Some(type_params) = type_params {
+                                                ty = self.analyzer.expand_type_params(&type_params, ty, Default::default()).unwrap();
+                                            }
                                         }
 
                                         element.ty = box ty;
@@ -2489,12 +2527,14 @@ impl Expander<'_, '_, '_> {
                         let ty = if v { true_type } else { false_type };
                         let (_, mut ty) = unwrap_type(&**ty);
 
-                        let type_params = self
-                            .analyzer
-                            .infer_ts_infer_types(span, &extends_type, &obj_type, Default::default())
-                            .ok();
-                        if let Some(type_params) = type_params {
-                            ty = self.analyzer.expand_type_params(&type_params, ty, Default::default()).unwrap();
+                        if v {
+                            let type_params = self
+                                .analyzer
+                                .infer_ts_infer_types(span, &extends_type, &obj_type, Default::default())
+                                .ok();
+                            if let Some(type_params) = type_params {
+                                ty = self.analyzer.expand_type_params(&type_params, ty, Default::default()).unwrap();
+                            }
                         }
 
                         return ty;
@@ -2547,7 +2587,7 @@ impl Expander<'_, '_, '_> {
                 mut check_type,
                 mut extends_type,
                 mut true_type,
-                mut false_type,
+                false_type,
                 metadata,
                 ..
             }) => {
@@ -2560,15 +2600,32 @@ impl Expander<'_, '_, '_> {
                     .infer_ts_infer_types(self.span, &extends_type, &check_type, Default::default())
                     .ok();
 
-                if let Some(type_params) = type_params {
+                // Types declared with `infer` are visible only in the true branch.
+                if let Some(mut type_params) = type_params {
+                    // An `infer` type which cannot be inferred from a concrete check type
+                    // falls back to its constraint, or `unknown`.
+                    let mut usage = TypeParamUsageFinder::default();
+                    check_type.visit_with(&mut usage);
+                    if usage.params.is_empty() {
+                        let mut decls = InferTypeDeclFinder::default();
+                        extends_type.visit_with(&mut decls);
+                        for param in decls.params {
+                            type_params.entry(param.name.clone()).or_insert_with(|| match param.constraint {
+                                Some(constraint) => *constraint,
+                                None => Type::unknown(span, Default::default()),
+                            });
+                        }
+                    }
+
+                    // `infer` types declared by nested conditional types shadow ours.
+                    let mut finder = InferTypeDeclFinder::default();
+                    true_type.visit_with(&mut finder);
+                    type_params.retain(|name, _| !finder.names.contains(name));
+
                     true_type = box self
                         .analyzer
                         .expand_type_params(&type_params, *true_type, Default::default())
                         .unwrap();
-                    false_type = box self
-                        .analyzer
-                        .expand_type_params(&type_params, *false_type, Default::default())
-                        .unwrap();
                 }
 
                 if check_type.is_class() {
@@ -2680,3 +2737,18 @@ impl Fold<TypeElement> for Expander<'_, '_, '_> {
         node
     }
 }
+
+/// Finds types declared with `infer`.
+#[derive(Debug, Default)]
+struct InferTypeDeclFinder {
+    names: FxHashSet<Id>,
+    params: Vec<TypeParam>,
+}
+
+impl Visit<InferType> for InferTypeDeclFinder {
+    fn visit(&mut self, ty: &InferType) {
+        if self.names.insert(ty.type_param.name.clone()) {
+            self.params.push(ty.type_param.clone());
+        }
+    }
+}
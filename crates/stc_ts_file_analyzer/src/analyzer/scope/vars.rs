@@ -5,7 +5,7 @@ use rnode::{FoldWith, NodeId};
 use stc_ts_ast_rnode::{RBindingIdent, RExpr, RIdent, RNumber, RObjectPatProp, RPat, RStr, RTsEntityName, RTsLit};
 use stc_ts_errors::{debug::dump_type_as_string, DebugExt, Error};
 use stc_ts_type_ops::{widen::Widen, Fix};
-use stc_ts_types::{Array, Key, KeywordType, LitType, ModuleId, Ref, Type, TypeLit, TypeParamInstantiation, Union};
+use stc_ts_types::{Array, Key, LitType, ModuleId, Ref, Type, TypeLit, TypeParamInstantiation, Union};
 use stc_ts_utils::{run, PatExt};
 use stc_utils::{cache::Freeze, TryOpt};
 use swc_common::{Span, Spanned, SyntaxContext, DUMMY_SP};
@@ -20,6 +20,8 @@ use crate::{
         util::{opt_union, ResultExt},
         Analyzer, Ctx,
     },
+    ty::TypeExt,
+    type_facts::TypeFacts,
     validator::ValidateWith,
     VResult,
 };
@@ -480,23 +482,11 @@ impl Analyzer<'_, '_> {
                                     .ok()
                             });
 
+                            // `undefined` is replaced by the default value.
+                            //
+                            // const { a = 1 } = {} as { a?: number };
                             if prop.value.is_some() {
-                                prop_ty = prop_ty.map(|ty| {
-                                    // Optional
-                                    ty.map(|ty| {
-                                        Type::new_union(
-                                            span,
-                                            vec![
-                                                ty,
-                                                Type::Keyword(KeywordType {
-                                                    span,
-                                                    kind: TsKeywordTypeKind::TsUndefinedKeyword,
-                                                    metadata: Default::default(),
-                                                }),
-                                            ],
-                                        )
-                                    })
-                                });
+                                prop_ty = prop_ty.map(|ty| ty.map(|ty| self.apply_type_facts_to_type(TypeFacts::NEUndefined, ty)));
                             }
 
                             match prop_ty {
@@ -512,6 +502,10 @@ impl Analyzer<'_, '_> {
                                                 )
                                                 .context("tried to validate default value of an assignment pattern")
                                                 .report(&mut self.storage)
+                                                .map(|ty| match opts.kind {
+                                                    VarKind::Var(VarDeclKind::Const) => ty,
+                                                    _ => ty.generalize_lit(),
+                                                })
                                                 .freezed();
 
                                             let default = opt_union(span, default_prop_ty, default_value_type).freezed();
@@ -35,7 +35,8 @@ impl Analyzer<'_, '_> {
     fn validate_loop_body_with_scope(&mut self, test: Option<&RExpr>, body: &RStmt) -> VResult<()> {
         let mut orig_facts = self.cur_facts.take();
 
-        let mut prev_facts = orig_facts.true_facts.take();
+        let orig_true_facts = orig_facts.true_facts.take();
+        let mut prev_facts = orig_true_facts.clone();
         let prev_false_facts = orig_facts.false_facts.take();
         let mut facts_of_prev_body_eval = CondFacts::default();
         let mut last = false;
@@ -70,7 +71,6 @@ impl Analyzer<'_, '_> {
             facts_from_body.excludes.clear();
 
             if last {
-                prev_facts += facts_from_body;
                 break;
             }
 
@@ -87,7 +87,9 @@ impl Analyzer<'_, '_> {
             prev_facts += facts_from_body;
         }
 
-        self.cur_facts.true_facts += prev_facts;
+        // Narrowing done in the body (e.g. `if (!x) continue;`) is valid only for
+        // the rest of the body, as the body may not be evaluated at all.
+        self.cur_facts.true_facts += orig_true_facts;
         self.cur_facts.false_facts += prev_false_facts;
 
         Ok(())
@@ -112,7 +112,12 @@ impl Analyzer<'_, '_> {
         self.check_for_inifinite_loop(test.as_ref().unwrap_or(&always_true), &node.body);
 
         node.update.visit_with(self);
-        node.body.validate_with(self)?;
+
+        // Narrowing done in the body is not valid after the loop.
+        let facts = self.cur_facts.clone();
+        let res = node.body.validate_with(self);
+        self.cur_facts = facts;
+        res?;
 
         Ok(())
     }
@@ -50,6 +50,10 @@ impl Analyzer<'_, '_> {
             var.decls.visit_with(a);
         });
 
+        for decl in &var.decls {
+            self.store_aliased_discriminant(var.kind, decl);
+        }
+
         // Set type of tuples.
         for decl in &var.decls {
             match &decl.name {
@@ -599,8 +603,11 @@ impl Analyzer<'_, '_> {
                                                 break;
                                             }
                                             RPat::Array(RArrayPat { ref elems, .. }) => {
-                                                let span = elems[i].span();
-                                                type_errors.push(Error::ImplicitAny { span }.context("tuple type widenning"));
+                                                // Holes and elements not bound by the pattern don't declare a variable.
+                                                if let Some(Some(elem)) = elems.get(i) {
+                                                    let span = elem.span();
+                                                    type_errors.push(Error::ImplicitAny { span }.context("tuple type widenning"));
+                                                }
                                             }
                                             _ => {}
                                         }
@@ -182,6 +182,7 @@ fn get_env() -> Env {
         Rule {
             strict_null_checks: true,
             strict_function_types: true,
+            note_number_bigint_casts: true,
             ..Default::default()
         },
         EsVersion::latest(),
@@ -19,7 +19,7 @@ use stc_utils::{
     cache::{Freeze, ALLOW_DEEP_CLONE},
     debug_ctx,
     ext::{SpanExt, TypeVecExt},
-    stack,
+    stack, try_cache,
 };
 use swc_atoms::js_word;
 use swc_common::{util::take::Take, Span, Spanned, SyntaxContext, TypeEq};
@@ -37,6 +37,8 @@ mod index_signature;
 mod keyof;
 mod mapped;
 mod narrowing;
+#[cfg(test)]
+mod tests;
 mod type_param;
 
 /// All fields defaults to false.
@@ -311,21 +313,40 @@ impl Analyzer<'_, '_> {
                             Type::Union(check_type_union) => {
                                 let mut all = true;
                                 let mut types = vec![];
-                                for check_type in &check_type_union.types {
-                                    let res = self.extends(ty.span(), &check_type, &extends_type, Default::default());
-                                    if let Some(v) = res {
-                                        if v {
-                                            if !c.true_type.is_never() {
-                                                types.push(check_type.clone());
-                                            }
-                                        } else {
-                                            if !c.false_type.is_never() {
-                                                types.push(check_type.clone());
-                                            }
-                                        }
+
+                                // Members which are unions are distributed too.
+                                let mut members = vec![];
+                                let mut queue = check_type_union.types.iter().collect_vec();
+                                while let Some(member) = queue.pop() {
+                                    match member.normalize() {
+                                        Type::Union(u) => queue.extend(u.types.iter()),
+                                        _ => members.push(member),
+                                    }
+                                }
+                                members.reverse();
+
+                                for check_type in members {
+                                    let key = Conditional {
+                                        check_type: box check_type.clone(),
+                                        extends_type: box extends_type.clone().into_owned(),
+                                        ..c.clone()
+                                    };
+                                    // Memoize the result for each member, to avoid re-evaluating recursive
+                                    // conditional types.
+                                    let res = if self.scope.is_root() {
+                                        try_cache!(self.data.cache.distributive_conditional.entry(c.span).or_default(), key.clone(), {
+                                            Ok(self.distribute_conditional_member(ty.span(), &key))
+                                        })
                                     } else {
-                                        all = false;
-                                        break;
+                                        self.distribute_conditional_member(ty.span(), &key)
+                                    };
+                                    match res {
+                                        Some(Some(member)) => types.push(member),
+                                        Some(None) => {}
+                                        None => {
+                                            all = false;
+                                            break;
+                                        }
                                     }
                                 }
 
@@ -560,6 +581,20 @@ impl Analyzer<'_, '_> {
         res
     }
 
+    /// Calculates the result of a distributive conditional type for a member of
+    /// the union, which is the check type of `c`.
+    ///
+    /// Returns `Some(None)` if the member is removed.
+    fn distribute_conditional_member(&mut self, span: Span, c: &Conditional) -> Option<Option<Type>> {
+        let v = self.extends(span, &c.check_type, &c.extends_type, Default::default())?;
+        let branch = if v { &c.true_type } else { &c.false_type };
+        if branch.is_never() {
+            Some(None)
+        } else {
+            Some(Some((*c.check_type).clone()))
+        }
+    }
+
     fn reduce_conditional_type(
         &mut self,
         span: Span,
@@ -0,0 +1,29 @@
+use std::borrow::Cow;
+
+use crate::analyzer::{tests::test_two, Analyzer};
+
+fn num_cached_members(analyzer: &Analyzer) -> usize {
+    analyzer.data.cache.distributive_conditional.values().map(|cache| cache.len()).sum()
+}
+
+#[test]
+fn distributive_conditional_with_infer_is_cached() {
+    test_two(
+        "Flatten<Nested>",
+        "Flatten<Nested>;
+        type Flatten<T> = T extends readonly (infer U)[] ? Flatten<U> : T;
+        type Key = 'a' | 'b' | 'c';
+        type Nested = Key | Key[] | Key[][]",
+        |analyzer, l, r| {
+            analyzer.normalize(None, Cow::Owned(l), Default::default()).unwrap();
+
+            let cached = num_cached_members(analyzer);
+            assert_ne!(cached, 0);
+
+            // All members are resolved from the cache.
+            analyzer.normalize(None, Cow::Owned(r), Default::default()).unwrap();
+
+            assert_eq!(num_cached_members(analyzer), cached);
+        },
+    );
+}
@@ -36,24 +36,56 @@ struct StcError {
     code: usize,
 }
 
-fn get_env() -> Env {
+/// Options which are not supported by tsc can be enabled using directives like
+/// `// @reportExcessPropertiesInTypeCasts: true`.
+///
+/// Libraries can be specified using `// @lib: esnext`.
+fn get_env(src: &str) -> Env {
     let mut libs = vec![];
-    let ls = &["es2017.full", "es2016.full", "es2015.full"];
+    let mut ls = vec!["es2017.full", "es2016.full", "es2015.full"];
+
+    let mut rule = Rule {
+        strict_function_types: true,
+        ..Default::default()
+    };
+
+    for line in src.lines() {
+        if let Some(value) = line.trim().strip_prefix("// @reportExcessPropertiesInTypeCasts:") {
+            rule.report_excess_properties_in_type_casts = value.trim().parse().unwrap();
+        }
+        if let Some(value) = line.trim().strip_prefix("// @noteTupleLabelChangesInTypeCasts:") {
+            rule.note_tuple_label_changes_in_type_casts = value.trim().parse().unwrap();
+        }
+        if let Some(value) = line.trim().strip_prefix("// @noteReadonlyRemovalInTypeCasts:") {
+            rule.note_readonly_removal_in_type_casts = value.trim().parse().unwrap();
+        }
+        if let Some(value) = line.trim().strip_prefix("// @noteNumberBigIntCasts:") {
+            rule.note_number_bigint_casts = value.trim().parse().unwrap();
+        }
+        if let Some(value) = line.trim().strip_prefix("// @nonOverlappingTypeCastsAsWarnings:") {
+            rule.non_overlapping_type_casts_as_warnings = value.trim().parse().unwrap();
+        }
+        if let Some(value) = line.trim().strip_prefix("// @reportNeverNarrowedBlocks:") {
+            rule.report_never_narrowed_blocks = value.trim().parse().unwrap();
+        }
+        if let Some(value) = line.trim().strip_prefix("// @useDefineForClassFields:") {
+            rule.use_define_property_for_class_fields = value.trim().parse().unwrap();
+        }
+        if let Some(value) = line.trim().strip_prefix("// @strictNullChecks:") {
+            rule.strict_null_checks = value.trim().parse().unwrap();
+        }
+        if let Some(value) = line.trim().strip_prefix("// @lib:") {
+            ls = value.split(',').map(|s| s.trim()).collect();
+        }
+    }
+
     for s in ls {
         libs.extend(Lib::load(s))
     }
     libs.sort();
     libs.dedup();
 
-    Env::simple(
-        Rule {
-            strict_function_types: true,
-            ..Default::default()
-        },
-        EsVersion::latest(),
-        ModuleConfig::None,
-        &libs,
-    )
+    Env::simple(rule, EsVersion::latest(), ModuleConfig::None, &libs)
 }
 
 fn validate(input: &Path) -> Vec<StcError> {
@@ -64,18 +96,19 @@ fn validate(input: &Path) -> Vec<StcError> {
 
             let fm = cm.load_file(input).unwrap();
 
-            let env = get_env();
+            let env = get_env(&fm.src);
 
             let generator = module_id::ModuleIdGenerator::default();
             let path = Arc::new(FileName::Real(input.to_path_buf()));
 
             let mut node_id_gen = NodeIdGenerator::default();
+            let comments = StcComments::default();
             let mut module = {
                 let lexer = Lexer::new(
                     Syntax::Typescript(TsConfig { ..Default::default() }),
                     EsVersion::Es2021,
                     SourceFileInput::from(&*fm),
-                    None,
+                    Some(&comments),
                 );
                 let mut parser = Parser::new_from(lexer);
 
@@ -98,7 +131,7 @@ fn validate(input: &Path) -> Vec<StcError> {
                 // Don't print logs from builtin modules.
                 let _tracing = tracing::subscriber::set_default(logger(Level::DEBUG));
 
-                let mut analyzer = Analyzer::root(env.clone(), cm.clone(), Default::default(), box &mut storage, &NoopLoader, None);
+                let mut analyzer = Analyzer::root(env.clone(), cm.clone(), comments.clone(), box &mut storage, &NoopLoader, None);
                 module.visit_with(&mut analyzer);
             }
 
@@ -120,6 +153,7 @@ fn validate(input: &Path) -> Vec<StcError> {
 
     diagnostics
         .into_iter()
+        .filter(|d| d.level != swc_common::errors::Level::Note && d.level != swc_common::errors::Level::Warning)
         .map(|d| {
             let span = d.span.primary_span().unwrap();
             let cp = tester.cm.lookup_char_pos(span.lo());
@@ -151,18 +185,19 @@ fn errors(input: PathBuf) {
 
         let fm = cm.load_file(&input).unwrap();
 
-        let env = get_env();
+        let env = get_env(&fm.src);
 
         let generator = module_id::ModuleIdGenerator::default();
         let path = Arc::new(FileName::Real(input.to_path_buf()));
 
         let mut node_id_gen = NodeIdGenerator::default();
+        let comments = StcComments::default();
         let mut module = {
             let lexer = Lexer::new(
                 Syntax::Typescript(TsConfig { ..Default::default() }),
                 EsVersion::Es2021,
                 SourceFileInput::from(&*fm),
-                None,
+                Some(&comments),
             );
             let mut parser = Parser::new_from(lexer);
 
@@ -185,7 +220,7 @@ fn errors(input: PathBuf) {
             // Don't print logs from builtin modules.
             let _tracing = tracing::subscriber::set_default(logger(Level::DEBUG));
 
-            let mut analyzer = Analyzer::root(env.clone(), cm.clone(), Default::default(), box &mut storage, &NoopLoader, None);
+            let mut analyzer = Analyzer::root(env.clone(), cm.clone(), comments.clone(), box &mut storage, &NoopLoader, None);
             module.visit_with(&mut analyzer);
         }
 
@@ -217,18 +252,22 @@ fn pass_only(input: PathBuf) {
 
         let fm = cm.load_file(&input).unwrap();
 
-        let env = get_env();
+        let env = get_env(&fm.src);
 
         let generator = module_id::ModuleIdGenerator::default();
         let path = Arc::new(FileName::Real(input.to_path_buf()));
 
         let mut node_id_gen = NodeIdGenerator::default();
+        let comments = StcComments::default();
         let mut module = {
             let lexer = Lexer::new(
-                Syntax::Typescript(TsConfig { ..Default::default() }),
+                Syntax::Typescript(TsConfig {
+                    import_assertions: true,
+                    ..Default::default()
+                }),
                 EsVersion::Es2021,
                 SourceFileInput::from(&*fm),
-                None,
+                Some(&comments),
             );
             let mut parser = Parser::new_from(lexer);
 
@@ -251,12 +290,13 @@ fn pass_only(input: PathBuf) {
             // Don't print logs from builtin modules.
             let _tracing = tracing::subscriber::set_default(logger(Level::DEBUG));
 
-            let mut analyzer = Analyzer::root(env.clone(), cm.clone(), Default::default(), box &mut storage, &NoopLoader, None);
+            let mut analyzer = Analyzer::root(env.clone(), cm.clone(), comments.clone(), box &mut storage, &NoopLoader, None);
             module.visit_with(&mut analyzer);
         }
 
         let errors = ::stc_ts_errors::Error::flatten(storage.info.errors.into_iter().collect());
-        let ok = errors.is_empty();
+        // Warnings are allowed.
+        let ok = errors.iter().all(|err| err.is_warning());
 
         GLOBALS.set(env.shared().swc_globals(), || {
             for e in errors {
@@ -352,6 +392,12 @@ fn run_test(file_name: PathBuf, for_error: bool) -> Option<NormalizedOutput> {
                 suppress_excess_property_errors: false,
                 suppress_implicit_any_index_errors: false,
                 use_define_property_for_class_fields: false,
+                report_excess_properties_in_type_casts: false,
+                note_tuple_label_changes_in_type_casts: false,
+                note_readonly_removal_in_type_casts: false,
+                note_number_bigint_casts: false,
+                report_never_narrowed_blocks: false,
+                non_overlapping_type_casts_as_warnings: false,
             };
 
             for line in fm.src.lines() {
@@ -411,7 +457,7 @@ fn run_test(file_name: PathBuf, for_error: bool) -> Option<NormalizedOutput> {
                     let mut analyzer = Analyzer::root(
                         env,
                         cm.clone(),
-                        Default::default(),
+                        comments.clone(),
                         box &mut storage,
                         &NoopLoader,
                         if for_error {
@@ -0,0 +1,19 @@
+// Each level instantiates new types, so the assignment never repeats a pair
+// of types which is already being checked.
+
+interface A<T> {
+    value: T;
+    next: B<[T]>;
+}
+
+interface B<T> {
+    value: T;
+    next: A<[T]>;
+}
+
+declare let a: A<string>;
+declare let b: A<number>;
+
+a = b;
+
+export { }
@@ -0,0 +1,3 @@
+const s: string[] = Array(1, 2);
+
+export { }
@@ -0,0 +1,3 @@
+const n: number[] = new Array<string>();
+
+export { }
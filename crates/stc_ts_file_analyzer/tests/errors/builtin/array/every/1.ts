@@ -0,0 +1,12 @@
+declare const arr: (string | number)[];
+
+function isString(v: string | number): v is string {
+    return typeof v === "string";
+}
+
+// `some` does not narrow.
+if (arr.some(isString)) {
+    const a: string[] = arr;
+}
+
+export { }
@@ -0,0 +1,6 @@
+declare const arr: number[];
+
+// The type of the parameter of the predicate should match the element type.
+arr.every((v: string) => v.length > 0);
+
+export { }
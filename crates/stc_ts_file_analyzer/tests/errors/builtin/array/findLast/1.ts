@@ -0,0 +1,8 @@
+// @lib: esnext
+
+declare const arr: (string | number)[];
+
+// Without a type guard, the element type is not narrowed.
+const a: string | undefined = arr.findLast((v) => typeof v === "string");
+
+export { }
@@ -0,0 +1,6 @@
+declare const arr: number[];
+
+// Non-array values are kept, so the result is `(string | number)[]`.
+const a: number[] = arr.flatMap((x) => (x > 0 ? [x] : "none"));
+
+export { }
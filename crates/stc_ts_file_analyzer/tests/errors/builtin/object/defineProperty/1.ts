@@ -0,0 +1,6 @@
+const obj = {};
+Object.defineProperty(obj, "foo", { value: 1 });
+
+const foo: string = obj.foo;
+
+export { }
@@ -0,0 +1,6 @@
+// @lib: esnext
+
+// The target of a WeakRef should be an object.
+const ref = new WeakRef(1);
+
+export { }
@@ -0,0 +1,13 @@
+// @lib: esnext
+// @strictNullChecks: true
+
+interface Foo {
+    foo: string;
+}
+
+declare const foo: Foo;
+
+// `deref` may return undefined.
+const a: Foo = new WeakRef(foo).deref();
+
+export { }
@@ -0,0 +1,9 @@
+class A {
+    #x = 1;
+}
+
+declare let a: A;
+
+a.#x;
+
+export { }
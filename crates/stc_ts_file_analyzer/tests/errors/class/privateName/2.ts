@@ -0,0 +1,11 @@
+class A {
+    #x = 1;
+}
+
+class B {
+    foo(a: A) {
+        return a.#x;
+    }
+}
+
+export { }
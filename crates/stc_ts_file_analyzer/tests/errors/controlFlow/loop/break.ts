@@ -0,0 +1,12 @@
+declare let xs: string[];
+declare let y: string | number;
+
+for (const x of xs) {
+    if (typeof y === "number") break;
+    y.length;
+}
+
+// `y` may be a number if the loop is exited using `break`.
+y.length;
+
+export { }
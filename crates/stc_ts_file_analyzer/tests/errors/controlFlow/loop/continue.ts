@@ -0,0 +1,11 @@
+declare let xs: string[];
+declare let y: string | number;
+
+for (let i = 0; i < xs.length; i++) {
+    if (typeof y === "number") continue;
+    y.length;
+}
+
+y.length;
+
+export { }
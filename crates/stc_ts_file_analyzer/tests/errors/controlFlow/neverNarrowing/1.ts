@@ -0,0 +1,9 @@
+// @reportNeverNarrowedBlocks: true
+
+declare const x: string | number | boolean;
+
+if (typeof x === "string" && typeof x === "number") {
+    console.log("unreachable");
+}
+
+export { }
@@ -0,0 +1,6 @@
+const a = Symbol();
+let b = Symbol();
+
+const c: typeof a = b;
+
+export { }
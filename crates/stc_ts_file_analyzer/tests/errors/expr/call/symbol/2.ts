@@ -0,0 +1,3 @@
+const a = Symbol(true);
+
+export { }
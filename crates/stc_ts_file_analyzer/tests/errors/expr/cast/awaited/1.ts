@@ -0,0 +1,5 @@
+declare const s: string;
+
+s as Awaited<Promise<Promise<number>>>;
+
+export { }
@@ -0,0 +1,5 @@
+// @strictNullChecks: true
+
+const a = null as {};
+
+export { }
@@ -0,0 +1,5 @@
+// @strictNullChecks: true
+
+const a = undefined as {};
+
+export { }
@@ -0,0 +1,5 @@
+// @reportExcessPropertiesInTypeCasts: true
+
+({ a: 1, b: 2 } as { a: number });
+
+export { }
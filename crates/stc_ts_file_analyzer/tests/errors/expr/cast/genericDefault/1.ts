@@ -0,0 +1,10 @@
+interface Box<T, U = string> {
+    value: T;
+    extra: U;
+}
+
+declare const box: { value: number; extra: boolean };
+
+box as Box<number>;
+
+export { }
@@ -0,0 +1,12 @@
+// @strictNullChecks: true
+
+interface Foo {
+    foo: string;
+}
+
+declare let foo: Foo;
+
+// The type of the cast expression includes `null`.
+const a: Foo = foo as Foo | null;
+
+export { }
@@ -0,0 +1,5 @@
+// @noteNumberBigIntCasts: true
+
+0n as number;
+
+export { }
@@ -0,0 +1,5 @@
+// @noteNumberBigIntCasts: true
+
+0 as bigint;
+
+export { }
@@ -0,0 +1,7 @@
+// @noteReadonlyRemovalInTypeCasts: true
+
+declare const immutable: readonly [number, string];
+
+immutable as [number, string];
+
+export { }
@@ -0,0 +1,7 @@
+// @noteTupleLabelChangesInTypeCasts: true
+
+declare let t: [x: number, y: number];
+
+t as [y: number, x: number];
+
+export { }
@@ -0,0 +1,9 @@
+// @lib: es5
+
+declare const value: object;
+
+// `Map` and `Set` don't exist in es5.
+value as Map<string, number>;
+value as Set<string> | undefined;
+
+export { }
@@ -0,0 +1,7 @@
+// @nonOverlappingTypeCastsAsWarnings: false
+
+declare const s: string;
+
+s as number;
+
+export { }
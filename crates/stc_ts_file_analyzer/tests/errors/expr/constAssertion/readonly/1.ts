@@ -0,0 +1,5 @@
+const a = [1, [2, 3]] as const;
+
+const a1: [1, [2, 3]] = a;
+
+export { }
@@ -0,0 +1,5 @@
+const b = { c: { d: 1 } } as const;
+
+b.c.d = 2;
+
+export { }
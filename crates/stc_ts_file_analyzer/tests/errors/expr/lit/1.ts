@@ -0,0 +1,6 @@
+const a = 1n;
+
+// A bigint literal is not a number.
+const b: number = a;
+
+export { }
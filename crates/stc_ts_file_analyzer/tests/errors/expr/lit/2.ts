@@ -0,0 +1,5 @@
+const a = 1_000;
+
+const b: 100 = a;
+
+export { }
@@ -0,0 +1,5 @@
+function foo() {
+    super();
+}
+
+export { }
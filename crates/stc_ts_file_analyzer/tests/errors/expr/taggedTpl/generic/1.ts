@@ -0,0 +1,5 @@
+declare function tag<T>(s: TemplateStringsArray, ...v: T[]): T[];
+
+const a: string[] = tag`a${1}b${2}`;
+
+export { }
@@ -0,0 +1,15 @@
+class Foo {
+    x = 1;
+}
+
+class Base {
+    kind(): this extends Foo ? "foo" : "other" {
+        return null as any;
+    }
+}
+
+declare const base: Base;
+
+const kind: "foo" = base.kind();
+
+export { }
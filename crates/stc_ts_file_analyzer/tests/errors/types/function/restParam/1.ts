@@ -0,0 +1,3 @@
+function foo(...rest: string[], x: number) {}
+
+export { }
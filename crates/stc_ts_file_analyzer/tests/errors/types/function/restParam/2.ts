@@ -0,0 +1,3 @@
+type Fn = (...rest: string[], x: number) => void;
+
+export { }
@@ -0,0 +1,6 @@
+declare const a: [string, ...(number | string)[]];
+
+// A rest element of a union type is not a union of rest elements.
+const b: [string, ...number[]] | [string, ...string[]] = a;
+
+export { }
@@ -0,0 +1,18 @@
+declare const arr: (string | number)[];
+
+function isString(v: string | number): v is string {
+    return typeof v === "string";
+}
+
+if (arr.every(isString)) {
+    const a: string[] = arr;
+}
+
+if (arr.every((v): v is number => typeof v === "number")) {
+    const b: number[] = arr;
+}
+
+const c: boolean = arr.every((v) => v !== "");
+const d: boolean = arr.some((v) => v === 1);
+
+export { }
@@ -0,0 +1,15 @@
+// @lib: esnext
+
+declare const arr: (string | number)[];
+
+const a: string | number | undefined = arr.findLast((v) => v === 1);
+const b: number = arr.findLastIndex((v) => typeof v === "string");
+
+const c: string | undefined = arr.findLast((v): v is string => typeof v === "string");
+
+declare const readonlyArr: readonly (string | number)[];
+
+const d: number | undefined = readonlyArr.findLast((v): v is number => typeof v === "number");
+const e: number = readonlyArr.findLastIndex((v) => v === "a");
+
+export { }
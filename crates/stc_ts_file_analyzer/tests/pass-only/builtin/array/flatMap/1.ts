@@ -0,0 +1,9 @@
+declare const arr: number[];
+
+const a: number[] = arr.flatMap((x) => (x > 0 ? [x] : x));
+
+declare const readonlyStrings: readonly string[];
+
+const b: string[] = arr.flatMap((x) => (x > 0 ? readonlyStrings : "none"));
+
+export { }
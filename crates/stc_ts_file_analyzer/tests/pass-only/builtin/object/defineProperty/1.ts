@@ -0,0 +1,16 @@
+const obj = {};
+Object.defineProperty(obj, "foo", { value: 1 });
+Object.defineProperty(obj, "bar", { value: "bar", writable: true });
+
+const foo: 1 = obj.foo;
+const bar: string = obj.bar;
+obj.bar = "baz";
+
+const accessor = {};
+Object.defineProperty(accessor, "baz", {
+    get() {
+        return 1;
+    },
+});
+
+export { }
@@ -0,0 +1,21 @@
+// @lib: esnext
+
+interface Foo {
+    foo: string;
+}
+
+declare const foo: Foo;
+
+const ref = new WeakRef(foo);
+const a: WeakRef<Foo> = ref;
+const b: Foo | undefined = ref.deref();
+
+const c = ref.deref();
+if (c) {
+    const d: string = c.foo;
+}
+
+const registry = new FinalizationRegistry((heldValue: string) => { });
+registry.register(foo, "foo");
+
+export { }
@@ -0,0 +1,17 @@
+class A {
+    #x = 1;
+
+    static isA(v: A | B): boolean {
+        if (#x in v) {
+            const a: A = v;
+            return true;
+        }
+        return false;
+    }
+}
+
+class B {
+    b = 1;
+}
+
+export { }
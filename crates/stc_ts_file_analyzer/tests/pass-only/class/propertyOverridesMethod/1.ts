@@ -0,0 +1,11 @@
+class Base {
+    run(value: number): string {
+        return "";
+    }
+}
+
+class Derived extends Base {
+    run = 1;
+}
+
+export { }
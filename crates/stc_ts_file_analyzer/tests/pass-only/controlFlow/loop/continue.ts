@@ -0,0 +1,22 @@
+declare let xs: (string | undefined)[];
+
+for (const x of xs) {
+    if (x === undefined) continue;
+    x.length;
+}
+
+for (let i = 0; i < xs.length; i++) {
+    const x = xs[i];
+    if (typeof x !== "string") {
+        continue;
+    }
+    x.length;
+}
+
+declare let y: string | number;
+while (xs.length) {
+    if (typeof y === "number") continue;
+    y.length;
+}
+
+export { }
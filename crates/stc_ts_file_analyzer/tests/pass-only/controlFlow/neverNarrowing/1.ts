@@ -0,0 +1,15 @@
+// @reportNeverNarrowedBlocks: true
+
+declare function assertNever(x: never): never;
+
+declare const x: string | number;
+
+if (typeof x !== "string" && typeof x !== "number") {
+    assertNever(x);
+}
+
+if (typeof x === "string") {
+    console.log(x);
+}
+
+export { }
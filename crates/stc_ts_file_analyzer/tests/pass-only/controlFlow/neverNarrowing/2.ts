@@ -0,0 +1,8 @@
+declare const x: string | number | boolean;
+
+// Not reported by default.
+if (typeof x === "string" && typeof x === "number") {
+    console.log("unreachable");
+}
+
+export { }
@@ -0,0 +1,16 @@
+// @strictNullChecks: true
+
+interface Foo {
+    foo: string;
+}
+
+declare const foo: Foo;
+declare const n: number;
+declare const b: boolean;
+
+const a = 1 as {};
+const c = n as {};
+const d = b as {};
+const e = foo as {};
+
+export { }
@@ -0,0 +1,9 @@
+({ a: 1, b: 2 } as { a: number });
+
+interface Foo {
+    a: number
+}
+
+<Foo>{ a: 1, b: 2 };
+
+export { }
@@ -0,0 +1,14 @@
+class Foo {
+    x = 1;
+}
+
+interface FooConstructor {
+    new(): Foo;
+}
+
+declare var f: () => Foo;
+
+const c = f as FooConstructor;
+const d = f as { new(): Foo };
+
+export { }
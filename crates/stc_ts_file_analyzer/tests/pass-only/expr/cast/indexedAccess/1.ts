@@ -0,0 +1,11 @@
+interface Values {
+    a: { name: string };
+    b: { name: string; age: number };
+}
+
+declare const value: unknown;
+
+const v = value as Values[keyof Values];
+const name: string = v.name;
+
+export { }
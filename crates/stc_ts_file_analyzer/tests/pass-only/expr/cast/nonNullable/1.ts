@@ -0,0 +1,12 @@
+// @strictNullChecks: true
+
+declare let x: string | null;
+declare let y: number | undefined | null;
+
+const a: string = x as NonNullable<typeof x>;
+(x as NonNullable<typeof x>).length;
+
+const b: number = y as NonNullable<typeof y>;
+(y as NonNullable<typeof y>).toFixed();
+
+export { }
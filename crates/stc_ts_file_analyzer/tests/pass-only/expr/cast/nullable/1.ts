@@ -0,0 +1,19 @@
+// @strictNullChecks: true
+
+interface Foo {
+    foo: string;
+}
+
+declare let foo: Foo;
+
+let a = foo as Foo | null;
+a = null;
+
+let b = foo as Foo | undefined;
+b = undefined;
+
+let c = (foo as Foo | null | undefined);
+c = null;
+c = undefined;
+
+export { }
@@ -0,0 +1,9 @@
+// @noteReadonlyRemovalInTypeCasts: true
+
+declare const mutable: [number, string];
+declare const immutable: readonly [number, string];
+
+mutable as readonly [number, string];
+immutable as readonly [number, string];
+
+export { }
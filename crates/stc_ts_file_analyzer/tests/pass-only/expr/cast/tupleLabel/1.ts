@@ -0,0 +1,6 @@
+declare let t: [x: number, y: number];
+
+t as [y: number, x: number];
+t as [x: number, y: number];
+
+export { }
@@ -0,0 +1,7 @@
+// @nonOverlappingTypeCastsAsWarnings: true
+
+declare const s: string;
+
+s as number;
+
+export { }
@@ -0,0 +1,10 @@
+const a = [1, [2, 3]] as const;
+const a1: readonly [1, readonly [2, 3]] = a;
+
+const b = { c: { d: 1 } } as const;
+const b1: { readonly c: { readonly d: 1 } } = b;
+
+const e = "e" as const;
+const e1: "e" = e;
+
+export { }
@@ -0,0 +1,9 @@
+const a = 1_000;
+const b: 1000 = a;
+let c = 1_000_000.5;
+const d: number = c;
+
+const e = 0xff_ff;
+const f: 65535 = e;
+
+export { }
@@ -0,0 +1,16 @@
+const a = 1n;
+const b: 1n = a;
+let c = 10n;
+const d: bigint = c;
+
+const e = 0x10n;
+const f: 16n = e;
+const g = 0o10n;
+const h: 8n = g;
+const i = 0b10n;
+const j: 2n = i;
+
+const k = 1_000n;
+const l: 1000n = k;
+
+export { }
@@ -0,0 +1,22 @@
+class Base {
+    foo() {}
+}
+
+class Derived extends Base {
+    foo() {
+        const obj = {
+            bar() {
+                return super.toString();
+            },
+            baz: () => super.foo(),
+        };
+    }
+}
+
+const obj = {
+    bar() {
+        return super.toString();
+    },
+};
+
+export { }
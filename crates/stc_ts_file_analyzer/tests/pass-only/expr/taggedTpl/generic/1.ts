@@ -0,0 +1,12 @@
+declare function tag<T>(s: TemplateStringsArray, ...v: T[]): T[];
+
+const a: number[] = tag`a${1}b${2}`;
+const b: string[] = tag`${"x"}`;
+
+declare const value: { name: string };
+const c: { name: string }[] = tag`${value}`;
+
+// There's no substitution to infer `T` from.
+const d: unknown[] = tag`no substitutions`;
+
+export { }
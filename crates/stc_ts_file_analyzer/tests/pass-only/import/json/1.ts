@@ -0,0 +1,9 @@
+import data from "./data.json" assert { type: "json" };
+import * as ns from "./data.json" assert { type: "json" };
+import quoted from "./data.json" assert { "type": "json" };
+
+data.foo.bar;
+ns.default;
+quoted.foo;
+
+export { }
@@ -0,0 +1,13 @@
+declare const arr: number[];
+declare const tuple: [string, number, boolean];
+
+const [, second] = arr;
+const [, b, c] = tuple;
+const [, , z] = tuple;
+
+const n: number = second;
+const nb: number = b;
+const bc: boolean = c;
+const bz: boolean = z;
+
+export { }
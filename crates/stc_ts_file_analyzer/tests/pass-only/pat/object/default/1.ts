@@ -0,0 +1,11 @@
+// @strictNullChecks: true
+
+declare let obj: { a?: number; b?: number };
+
+const { a = 1 } = obj;
+const n: number = a;
+
+let { b = "x" } = obj;
+const s: number | string = b;
+
+export { }
@@ -0,0 +1,39 @@
+class Foo {
+    x = 1;
+}
+
+abstract class Base {
+    kind(): this extends Foo ? "foo" : "other" {
+        return null as any;
+    }
+
+    abstract abstractKind(): this extends Foo ? "foo" : "other";
+
+    check() {
+        const kind = this.kind();
+        const abstractKind = this.abstractKind();
+    }
+}
+
+class WithX extends Base {
+    x = 1;
+
+    abstractKind(): this extends Foo ? "foo" : "other" {
+        return null as any;
+    }
+}
+
+class WithoutX extends Base {
+    abstractKind(): this extends Foo ? "foo" : "other" {
+        return null as any;
+    }
+}
+
+declare const withX: WithX;
+declare const withoutX: WithoutX;
+
+const a: "foo" = withX.kind();
+const b: "other" = withoutX.kind();
+const c: "foo" = withX.abstractKind();
+
+export { }
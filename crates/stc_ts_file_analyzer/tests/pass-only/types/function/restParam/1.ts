@@ -0,0 +1,9 @@
+function foo(x: number, ...rest: string[]) {}
+foo(1);
+foo(1, "a", "b");
+
+type Fn = (x: number, ...rest: string[]) => void;
+declare let fn: Fn;
+fn(1, "a");
+
+export { }
@@ -0,0 +1,7 @@
+declare const a: [boolean, ...number[]];
+const b: [true, ...number[]] | [false, ...number[]] = a;
+
+declare const c: [string, ...(number | string)[]];
+const d: [string, ...(number | string)[]] | [number] = c;
+
+export { }
@@ -0,0 +1,8 @@
+declare let f1: (x: number) => void;
+
+f1 = (x: number, y: number) => {};
+f1 = (x: number, y?: number) => {};
+f1 = (x: number, ...rest: number[]) => {};
+f1 = () => {};
+
+export { }
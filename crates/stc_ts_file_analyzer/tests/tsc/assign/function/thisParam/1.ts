@@ -0,0 +1,16 @@
+class Foo {
+    x = 1;
+}
+
+declare function takesVoidThis(cb: (this: void) => void): void;
+declare function takesFooThis(cb: (this: Foo) => void): void;
+
+function needsFoo(this: Foo) { }
+function needsVoid(this: void) { }
+
+takesVoidThis(needsFoo);
+takesVoidThis(needsVoid);
+takesFooThis(needsFoo);
+takesFooThis(needsVoid);
+
+export { }
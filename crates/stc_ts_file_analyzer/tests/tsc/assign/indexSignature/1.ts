@@ -0,0 +1,7 @@
+declare let wide: { [k: string]: number };
+declare let narrow: { [k: string]: 1 };
+
+wide = narrow;
+narrow = wide;
+
+export { }
@@ -0,0 +1,12 @@
+declare let str: { [k: string]: number };
+declare let num: { [k: number]: number };
+declare let narrowNum: { [k: number]: 1 };
+declare let narrowStr: { [k: string]: 1 };
+
+str = num;
+str = narrowNum;
+num = str;
+narrowNum = str;
+narrowNum = narrowStr;
+
+export { }
@@ -0,0 +1,3 @@
+const a: [number, number] = [1, 2, 3];
+
+export { }
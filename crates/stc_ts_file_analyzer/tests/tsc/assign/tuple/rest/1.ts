@@ -0,0 +1,5 @@
+const a: [number, ...number[]] = [1, 2, 3];
+const b: [string, ...number[]] = ["a"];
+const c: [string, ...number[]] = ["a", 1, 2];
+
+export { }
@@ -0,0 +1,7 @@
+abstract class A {
+    abstract foo() { }
+
+    abstract bar(): number;
+}
+
+export { }
@@ -0,0 +1,7 @@
+abstract class A {
+    abstract get foo(): number { return 1; }
+
+    abstract baz(): void { }
+}
+
+export { }
@@ -0,0 +1,12 @@
+class C {
+    get consistent() { return 0; }
+    set consistent(v: number) { }
+
+    protected get inconsistent() { return 0; }
+    public set inconsistent(v: number) { }
+
+    get moreAccessibleGetter() { return 0; }
+    protected set moreAccessibleGetter(v: number) { }
+}
+
+export { }
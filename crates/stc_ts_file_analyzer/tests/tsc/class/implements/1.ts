@@ -0,0 +1,35 @@
+type Point = { x: number; y: number };
+interface Named {
+    name: string;
+}
+type NamedPoint = Point & Named;
+
+class A implements Point {
+    x = 1;
+    y = 2;
+}
+
+class B implements NamedPoint {
+    x = 1;
+    y = 2;
+    name = "b";
+}
+
+class Base {
+    id = 1;
+}
+
+class C implements Base {
+    id = 2;
+}
+
+type Str = string;
+type Either = Point | Named;
+
+class D implements Str {}
+
+class E implements Either {
+    name = "e";
+}
+
+export { }
@@ -0,0 +1,22 @@
+class Foo {
+    a: string;
+
+    foo(): number {
+        return 1;
+    }
+}
+
+interface Foo {
+    a: string;
+    b: number;
+    bar(): string;
+}
+
+declare const foo: Foo;
+
+const a: string = foo.a;
+const b: number = foo.b;
+const c: number = foo.foo();
+const d: string = foo.bar();
+
+export { }
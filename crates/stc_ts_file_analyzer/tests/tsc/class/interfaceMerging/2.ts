@@ -0,0 +1,12 @@
+class Foo {
+    a: string;
+    b: number;
+}
+
+interface Foo {
+    a: number;
+    b: number;
+    c: boolean;
+}
+
+export { }
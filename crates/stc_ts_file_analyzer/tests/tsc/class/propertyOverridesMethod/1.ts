@@ -0,0 +1,21 @@
+// @useDefineForClassFields: true
+
+class Base {
+    run(value: number): string {
+        return "";
+    }
+
+    stop(): void {}
+}
+
+class Compatible extends Base {
+    run = (value: number) => "done";
+    stop = () => {};
+}
+
+class Incompatible extends Base {
+    run = 1;
+    stop = (force: boolean, reason: string) => {};
+}
+
+export { }
@@ -0,0 +1,19 @@
+// @useDefineForClassFields: true
+
+class A {
+    run(): string {
+        return "a";
+    }
+}
+
+class B extends A {
+    run(): string {
+        return "b";
+    }
+}
+
+class C extends B {
+    run = 1;
+}
+
+export { }
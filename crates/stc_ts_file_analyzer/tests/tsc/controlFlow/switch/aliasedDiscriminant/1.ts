@@ -0,0 +1,22 @@
+type Shape = { kind: "circle"; radius: number } | { kind: "square"; size: number };
+
+declare const shape: Shape;
+
+const kind = shape.kind;
+switch (kind) {
+    case "circle":
+        shape.radius;
+        break;
+    case "square":
+        shape.size;
+        break;
+}
+
+let reassignable = shape.kind;
+switch (reassignable) {
+    case "circle":
+        shape.radius;
+        break;
+}
+
+export { }
@@ -0,0 +1,11 @@
+declare function f(): number;
+declare let x: number;
+declare const obj: { a: number; b: number[] };
+
+f() = x;
+(f()) = x;
+obj.a = x;
+obj["a"] = x;
+obj.b[0] = x;
+
+export { }
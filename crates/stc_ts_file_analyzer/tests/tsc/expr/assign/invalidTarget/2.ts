@@ -0,0 +1,11 @@
+declare let x: number;
+declare let y: number | undefined;
+declare let arr: number[];
+declare const obj: { a: number };
+
+[obj.a, x] = arr;
+({ a: obj.a } = obj);
+[y!, (x as any)] = arr;
+y! = 1;
+
+export { }
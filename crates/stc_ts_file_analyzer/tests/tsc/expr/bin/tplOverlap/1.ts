@@ -0,0 +1,19 @@
+declare const s: string;
+declare const t: `a${string}`;
+
+s === t;
+t === s;
+t === "abc";
+"abc" === t;
+
+switch (s) {
+    case t:
+        break;
+}
+
+switch (t) {
+    case "abc":
+        break;
+}
+
+export { }
@@ -0,0 +1,11 @@
+const a = Array(3);
+const b = Array(1, 2);
+const c = new Array<string>();
+const d = new Array(3);
+
+const a1: string[] = a;
+const b1: number[] = b;
+const c1: string[] = c;
+const d1: boolean[] = d;
+
+export { }
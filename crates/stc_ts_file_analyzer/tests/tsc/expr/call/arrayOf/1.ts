@@ -0,0 +1,11 @@
+const a = Array.of(1, 2);
+const b = Array.of("a", "b");
+const c = Array.of<number | string>(1, "b");
+
+const n: number[] = a;
+const s: string[] = b;
+const u: (number | string)[] = c;
+
+const wrong: string[] = a;
+
+export { }
@@ -0,0 +1,13 @@
+declare const numbers: number[];
+declare const names: ReadonlyArray<string>;
+
+const a: number = numbers.indexOf(1);
+const b: number = numbers.lastIndexOf(1, 2);
+const c: number = names.indexOf("foo", -1);
+const d: number = names.lastIndexOf("foo");
+
+numbers.indexOf("1");
+names.lastIndexOf(1);
+numbers.indexOf(1, "2");
+
+export { }
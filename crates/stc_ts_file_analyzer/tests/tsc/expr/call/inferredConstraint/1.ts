@@ -0,0 +1,10 @@
+declare function f<T extends object>(x: T): T;
+declare function g<T extends string>(x: T): T;
+
+f({ a: 1 });
+f(1);
+
+g("a");
+g(1);
+
+export { }
@@ -0,0 +1,11 @@
+function f(a: string, b: number) {}
+
+declare const labeled: [first: number, second: number];
+declare const unlabeled: [number, number];
+declare const valid: [first: string, second: number];
+
+f(...labeled);
+f(...unlabeled);
+f(...valid);
+
+export { }
@@ -0,0 +1,13 @@
+const point = Object.freeze({ x: 1, y: 2 });
+const x: number = point.x;
+point.x = 3;
+
+const twice = Object.freeze(point);
+const y: number = twice.y;
+twice.y = 3;
+
+const numbers = Object.freeze([1, 2, 3]);
+const first: number = numbers[0];
+numbers.push(4);
+
+export { }
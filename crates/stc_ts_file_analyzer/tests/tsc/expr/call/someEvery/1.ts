@@ -0,0 +1,6 @@
+declare const arr: number[];
+
+arr.some((v: string) => v.length > 0);
+arr.some((v) => v > 0);
+
+export { }
@@ -0,0 +1,12 @@
+const a = Symbol();
+let b = Symbol();
+const c = Symbol("c");
+const d = Symbol.for("d");
+
+const e: typeof a = a;
+const f: symbol = b;
+const g: symbol = d;
+b = Symbol();
+b = c;
+
+export { }
@@ -0,0 +1,14 @@
+type A = { a: number };
+type AB = { a: number; b: string };
+type AliasOfA = A;
+
+declare let a: A;
+declare let ab: AB;
+
+a as AB;
+ab as A;
+a as AliasOfA;
+ab as AliasOfA;
+({ a: 1 }) as AliasOfA;
+
+export { }
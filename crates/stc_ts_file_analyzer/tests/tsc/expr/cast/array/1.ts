@@ -0,0 +1,14 @@
+interface Foo {
+    foo: string;
+}
+
+declare let nums: number[];
+declare let anys: any[];
+
+nums as unknown[];
+nums as any[];
+anys as Foo[];
+nums as string[];
+nums as Foo[];
+
+export { }
@@ -0,0 +1,9 @@
+declare let a: Array<number>;
+declare let b: number[];
+
+a as number[];
+b as Array<number>;
+<number[]>a;
+<Array<number>>b;
+
+export { }
@@ -0,0 +1,11 @@
+declare const n: number;
+declare const s: string;
+
+n as Awaited<Promise<number>>;
+n as Awaited<Promise<Promise<number>>>;
+(n as unknown) as Awaited<Promise<Promise<number>>>;
+
+s as Awaited<Promise<number>>;
+s as Awaited<Promise<Promise<number>>>;
+
+export { }
@@ -0,0 +1,10 @@
+declare const a: boolean;
+declare const b: true | false;
+declare const c: false | true;
+
+a as true | false;
+b as boolean;
+c as boolean;
+a as false | true;
+
+export { }
@@ -0,0 +1,17 @@
+type UserId = number & { __brand: "user" };
+type PostId = number & { __brand: "post" };
+
+declare const userId: UserId;
+declare const postId: PostId;
+declare const n: number;
+
+userId as number;
+postId as number;
+n as UserId;
+n as PostId;
+userId as UserId;
+
+userId as PostId;
+postId as UserId;
+
+export { }
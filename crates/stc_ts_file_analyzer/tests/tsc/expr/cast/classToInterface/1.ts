@@ -0,0 +1,27 @@
+class Foo {
+    a = 1;
+    b = "";
+}
+
+class Disjoint {
+    c = true;
+}
+
+class WithPrivate {
+    private a = 1;
+}
+
+interface Bar {
+    a: number;
+}
+
+declare const foo: Foo;
+declare const disjoint: Disjoint;
+declare const withPrivate: WithPrivate;
+
+foo as Bar;
+foo as { a: number };
+disjoint as Bar;
+withPrivate as Bar;
+
+export { }
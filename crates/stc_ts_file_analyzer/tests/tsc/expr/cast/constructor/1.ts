@@ -0,0 +1,24 @@
+class A {
+    constructor(public value: number) {}
+}
+
+class B {
+    constructor(public value: number) {}
+}
+
+class C extends A {
+    static create() {
+        return new C(1);
+    }
+}
+
+class D {
+    constructor(public name: string) {}
+}
+
+A as typeof B;
+A as typeof C;
+C as typeof A;
+A as typeof D;
+
+export { }
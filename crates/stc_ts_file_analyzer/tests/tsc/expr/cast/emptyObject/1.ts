@@ -0,0 +1,20 @@
+interface Foo {
+    foo: string;
+}
+
+declare const foo: Foo;
+declare const n: number;
+declare const s: string;
+declare const fn: () => void;
+
+const a = 1 as {};
+const b = "a" as {};
+const c = n as {};
+const d = s as {};
+const e = foo as {};
+const f = fn as {};
+const g = [1, 2] as {};
+const h = null as {};
+const i = undefined as {};
+
+export { }
@@ -0,0 +1,15 @@
+enum N {
+    A,
+    B,
+}
+
+declare let n: number;
+declare let e: N;
+
+N.A as number;
+e as number;
+n as N;
+n as N.A;
+N.A as string;
+
+export { }
@@ -0,0 +1,15 @@
+enum S {
+    A = "a",
+    B = "b",
+}
+
+declare let s: string;
+declare let e: S;
+
+S.A as string;
+e as string;
+s as S;
+s as S.A;
+S.A as number;
+
+export { }
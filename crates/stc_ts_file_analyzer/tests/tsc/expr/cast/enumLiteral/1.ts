@@ -0,0 +1,12 @@
+enum Color {
+    Red = "red",
+    Green = "green",
+}
+
+Color.Red as "red";
+"red" as Color.Red;
+
+Color.Red as "green";
+"green" as Color.Red;
+
+export { }
@@ -0,0 +1,9 @@
+[1, 2, 3] as [number];
+["a", "b"] as [string];
+
+[1, 2, 3] as [number, number, number];
+
+declare const t: [number, number, number];
+t as [number];
+
+export { }
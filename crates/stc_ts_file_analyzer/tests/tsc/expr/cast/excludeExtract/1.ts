@@ -0,0 +1,10 @@
+declare const a: "a";
+declare const b: "b";
+
+a as Exclude<"a" | "b", "b">;
+b as Exclude<"a" | "b", "b">;
+
+a as Extract<"a" | "b", "a">;
+b as Extract<"a" | "b", "a">;
+
+export { }
@@ -0,0 +1,19 @@
+interface Foo {
+    foo: string;
+}
+
+declare let n: number;
+declare let s: string;
+
+// @ts-expect-error
+n as string;
+
+// @ts-expect-error
+s as Foo;
+
+// @ts-expect-error
+n as 1;
+
+s as string;
+
+export { }
@@ -0,0 +1,17 @@
+declare let n: number;
+
+// @ts-expect-error
+const x: string = 1 as number;
+
+// @ts-expect-error
+const pair = [n as string, n as number];
+
+// @ts-expect-error
+
+n as string;
+
+// @ts-expect-error
+// @ts-expect-error
+n as string;
+
+export { }
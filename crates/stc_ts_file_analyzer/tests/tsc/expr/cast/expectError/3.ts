@@ -0,0 +1,8 @@
+// @noteReadonlyRemovalInTypeCasts: true
+
+declare const immutable: readonly [number, string];
+
+// @ts-expect-error
+immutable as [number, string];
+
+export { }
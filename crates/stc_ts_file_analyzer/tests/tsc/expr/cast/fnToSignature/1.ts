@@ -0,0 +1,19 @@
+class Foo {
+    x = 1;
+}
+
+interface Callable {
+    (): Foo;
+    name: string;
+}
+
+interface NumberCallable {
+    (a: number, b: number): number;
+}
+
+declare var f: () => Foo;
+
+f as Callable;
+f as NumberCallable;
+
+export { }
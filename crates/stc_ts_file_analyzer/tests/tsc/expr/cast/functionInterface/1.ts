@@ -0,0 +1,11 @@
+declare const f: Function;
+declare const g: (x: number) => void;
+
+const a = f as (x: number) => void;
+const b = f as () => string;
+const c = g as Function;
+
+a(1);
+b().toUpperCase();
+
+export { }
@@ -0,0 +1,22 @@
+interface Box<T, U = string> {
+    value: T;
+    extra: U;
+}
+
+interface List<T, U = T[]> {
+    head: T;
+    rest: U;
+}
+
+declare const box: { value: number; extra: string };
+declare const list: { head: number; rest: number[] };
+
+const a = box as Box<number>;
+const b = list as List<number>;
+const c = [1, 2, 3] as ReadonlyArray<number>;
+
+a.extra;
+b.rest;
+c.length;
+
+export { }
@@ -0,0 +1,13 @@
+function open<Args extends unknown[]>() {
+    return [1, "x"] as [...Args];
+}
+
+function anyArgs<Args extends any[]>() {
+    return [true] as [...Args];
+}
+
+function fromParam<Args extends unknown[]>(value: [number, string]) {
+    return value as [...Args];
+}
+
+export { }
@@ -0,0 +1,10 @@
+declare var record: Record<string, unknown>;
+declare var numbers: Record<string, number>;
+declare var obj: { a: number };
+
+record as { a: number };
+numbers as { a: number };
+numbers as { a: string };
+obj as Record<string, unknown>;
+
+export { }
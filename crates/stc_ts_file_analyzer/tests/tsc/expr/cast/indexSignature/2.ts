@@ -0,0 +1,17 @@
+interface Numbers {
+    [key: string]: number;
+}
+
+declare const numbers: Numbers;
+declare const obj: { a: number; b: number };
+declare const strs: { a: string };
+
+({ a: 1 }) as Numbers;
+obj as Numbers;
+numbers as { a: number };
+numbers as { a: number; b: number };
+
+strs as Numbers;
+numbers as { a: string };
+
+export { }
@@ -0,0 +1,12 @@
+interface Values {
+    a: { name: string };
+    b: { name: string; age: number };
+}
+
+declare const value: unknown;
+
+(value as Values[keyof Values]).name;
+(value as Values[keyof Values]).age;
+(value as Values["b"]).age;
+
+export { }
@@ -0,0 +1,31 @@
+interface P {
+    p: number;
+}
+
+interface C extends P {
+    c?: string;
+}
+
+interface D extends P {
+    d?: number;
+}
+
+interface E {
+    e: boolean;
+}
+
+interface F extends C {
+    f: string;
+}
+
+declare var p: P;
+declare var c: C;
+declare var f: F;
+
+c as D;
+c as E;
+p as F;
+f as P;
+f as D;
+
+export { }
@@ -0,0 +1,28 @@
+interface A {
+    a: string;
+}
+
+interface B {
+    b: number;
+}
+
+interface Base {
+    base: boolean;
+}
+
+interface C extends Base {
+    c: string;
+}
+
+declare const ab: A & B;
+declare const ac: A & C;
+
+const a = ab as A;
+const b = ab as B;
+const c = ac as Base;
+
+a.a;
+b.b;
+c.base;
+
+export { }
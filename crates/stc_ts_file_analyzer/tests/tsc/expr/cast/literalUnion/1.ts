@@ -0,0 +1,9 @@
+type Letter = "a" | "b" | "c" | "d" | "e" | "f" | "g" | "h" | "i" | "j" | "k" | "l" | "m"
+    | "n" | "o" | "p" | "q" | "r" | "s" | "t" | "u" | "v" | "w" | "y" | "z";
+
+"x" as Letter;
+"a" as Letter;
+"z" as Letter;
+1 as Letter;
+
+export { }
@@ -0,0 +1,19 @@
+interface Foo {
+    a: number;
+    b: string;
+}
+
+declare const foo: Foo;
+declare const readonlyFoo: Readonly<Foo>;
+declare const partialFoo: Partial<Foo>;
+
+foo as Readonly<Foo>;
+foo as Partial<Foo>;
+
+readonlyFoo as Foo;
+readonlyFoo as Partial<Foo>;
+
+partialFoo as Foo;
+partialFoo as Readonly<Foo>;
+
+export { }
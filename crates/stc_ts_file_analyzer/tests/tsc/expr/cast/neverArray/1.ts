@@ -0,0 +1,10 @@
+declare const empty: never[];
+declare const numbers: number[];
+
+empty as number[];
+empty as string[];
+empty as Array<{ a: number }>;
+numbers as never[];
+[] as number[];
+
+export { }
@@ -0,0 +1,6 @@
+const a = { a: 1 } as { a: number } | null;
+const b = { a: 1 } as { a: number } | undefined;
+const c = [1, "a"] as [number, string] | null;
+const d = "x" as number | null;
+
+export { }
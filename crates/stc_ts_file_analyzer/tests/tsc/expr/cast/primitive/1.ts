@@ -0,0 +1,37 @@
+declare let n: number;
+declare let s: string;
+declare let b: boolean;
+declare let big: bigint;
+
+declare let one: 1;
+declare let a: "a";
+declare let t: true;
+
+n as string;
+n as boolean;
+n as bigint;
+s as number;
+s as boolean;
+s as bigint;
+b as number;
+b as string;
+b as bigint;
+big as number;
+big as string;
+big as boolean;
+
+n as 1;
+s as "a";
+b as true;
+n as "a";
+s as 1;
+b as 1;
+
+one as number;
+a as string;
+t as boolean;
+one as string;
+a as number;
+t as string;
+
+export { }
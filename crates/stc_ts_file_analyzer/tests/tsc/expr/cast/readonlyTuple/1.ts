@@ -0,0 +1,12 @@
+declare const mutable: [number, string];
+declare const immutable: readonly [number, string];
+
+mutable as readonly [number, string];
+immutable as [number, string];
+mutable as readonly [1, "a"];
+immutable as [1, "a"];
+
+mutable as readonly [string, number];
+immutable as [string, number];
+
+export { }
@@ -0,0 +1,18 @@
+interface Bar {
+    name: string;
+}
+
+interface I {
+    foo(this: Bar): void;
+}
+
+({ foo(this: Bar) {} }) as I;
+({ foo() {} }) as I;
+({ foo(this: number) {} }) as I;
+
+declare function f(this: Bar, x: number): void;
+
+f as { (x: number): void };
+f as { (this: Bar, x: number): void };
+
+export { }
@@ -0,0 +1,8 @@
+declare const a: [number, string];
+declare const b: [string, number];
+
+a as [number, string] | [string, number];
+b as [number, string] | [string, number];
+[1, "x"] as [number, string] | [string, number];
+
+export { }
@@ -0,0 +1,13 @@
+interface Foo {
+    foo: string;
+}
+
+declare const foo: Foo;
+declare const missing: NotFound;
+
+foo as NotFound;
+foo as NotFound<number>;
+missing as Foo;
+missing as string;
+
+export { }
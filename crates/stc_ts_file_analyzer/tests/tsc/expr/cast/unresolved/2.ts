@@ -0,0 +1,8 @@
+declare const n: number;
+
+n as Missing;
+"foo" as Missing;
+<Missing>n;
+({ a: 1 }) as Missing<string>;
+
+export { }
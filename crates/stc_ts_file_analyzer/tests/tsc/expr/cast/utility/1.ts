@@ -0,0 +1,14 @@
+function makeThing() {
+    return { a: 1 };
+}
+
+class Foo {
+    x = 1;
+}
+
+const a = { a: 2 } as ReturnType<typeof makeThing>;
+const b = "str" as ReturnType<typeof makeThing>;
+const c = new Foo() as InstanceType<typeof Foo>;
+const d = 1 as InstanceType<typeof Foo>;
+
+export { }
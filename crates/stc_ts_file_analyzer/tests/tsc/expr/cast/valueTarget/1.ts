@@ -0,0 +1,18 @@
+const someValue = 1;
+
+class Foo {
+    foo = "";
+}
+
+enum E {
+    A,
+    B,
+}
+
+declare const x: unknown;
+
+x as someValue;
+x as Foo;
+x as E;
+
+export { }
@@ -0,0 +1,32 @@
+interface Animal {
+    name: string;
+}
+
+interface Dog extends Animal {
+    bark(): void;
+}
+
+// Covariant in `T`.
+interface Producer<T> {
+    get(): T;
+}
+
+// Contravariant in `T`.
+interface Consumer<T> {
+    set(value: T): void;
+}
+
+declare const dogs: Producer<Dog>;
+declare const animals: Producer<Animal>;
+declare const dogSink: Consumer<Dog>;
+declare const animalSink: Consumer<Animal>;
+
+dogs as Producer<Animal>;
+animals as Producer<Dog>;
+dogSink as Consumer<Animal>;
+animalSink as Consumer<Dog>;
+
+declare const strings: Producer<string>;
+strings as Producer<number>;
+
+export { }
@@ -0,0 +1,17 @@
+declare global {
+    var augmented: number;
+}
+
+const a: number = globalThis.augmented;
+const b: string = globalThis.augmented;
+
+globalThis.augmented = 1;
+globalThis.augmented = "a";
+
+const p: (s: string) => number = globalThis.parseInt;
+const q: (s: string) => number = globalThis["parseInt"];
+
+globalThis.notDeclared;
+globalThis["notDeclared"];
+
+export { }
@@ -0,0 +1,20 @@
+const obj = {
+    a: 1,
+    b: "foo",
+    c: true,
+};
+
+declare const k: keyof typeof obj;
+
+const v = obj[k];
+const ok: number | string | boolean = v;
+const bad: number = v;
+
+if (k === "a") {
+    const n: number = obj[k];
+}
+
+declare const l: "b";
+const s: string = obj[l];
+
+export { }
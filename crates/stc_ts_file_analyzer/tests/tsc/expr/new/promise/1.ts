@@ -0,0 +1,14 @@
+new Promise<number>((resolve, reject) => {
+    const r: (reason?: any) => void = reject;
+
+    reject();
+    reject(new Error("failed"));
+    reject("failed");
+    resolve(1);
+});
+
+new Promise<number>((resolve, reject) => {
+    const r: (reason: string) => number = reject;
+});
+
+export { }
@@ -0,0 +1,22 @@
+class Foo {
+    constructor(a: number, b: string) { }
+}
+
+declare const ok: [number, string];
+declare const bad: [number, number];
+
+new Foo(...ok);
+new Foo(...bad);
+
+class Bar {
+    constructor(a: number, ...rest: string[]) { }
+}
+
+declare const okRest: [number, string, string];
+declare const badRest: [number, string, number];
+
+new Bar(...okRest);
+new Bar(...badRest);
+new Bar(1, ...okRest.slice(1) as string[]);
+
+export { }
@@ -0,0 +1,10 @@
+const a = new Int32Array(3);
+const b = new Uint8Array([1, 2, 3]);
+const c = new Float64Array(new ArrayBuffer(8), 0, 1);
+
+const i: Int32Array = a;
+const u: Uint8Array = b;
+const f: Float64Array = c;
+const n: number = b[0];
+
+export { }
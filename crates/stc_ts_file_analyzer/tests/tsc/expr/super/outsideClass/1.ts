@@ -0,0 +1,24 @@
+class Base {
+    foo() {}
+}
+
+class Derived extends Base {
+    constructor() {
+        super();
+    }
+
+    foo() {
+        super.foo();
+        const f = () => super.foo();
+
+        function inner() {
+            super.foo();
+        }
+    }
+}
+
+function outer() {
+    super.foo();
+}
+
+export { }
@@ -0,0 +1,9 @@
+declare const tuple: [string, number, boolean];
+
+const [, b] = tuple;
+const [, , c] = tuple;
+
+const s1: string = b;
+const s2: string = c;
+
+export { }
@@ -0,0 +1,10 @@
+declare let obj: { a?: number; b?: number };
+
+const { a = 1 } = obj;
+const n: number = a;
+
+let { b = "x" } = obj;
+const s: number | string = b;
+const m: number = b;
+
+export { }
@@ -0,0 +1,12 @@
+type First<T> = T extends [infer U, ...any[]] ? U : "none";
+
+const a: First<[1, 2]> = 1;
+const b: First<[]> = "none";
+const c: First<string> = "none";
+
+type ElementOrSelf<T> = T extends (infer U)[] ? U : T;
+
+const d: ElementOrSelf<number[]> = 1;
+const e: ElementOrSelf<string> = "a";
+
+export { }
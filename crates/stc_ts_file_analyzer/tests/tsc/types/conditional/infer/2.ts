@@ -0,0 +1,11 @@
+type Unwrap<T> = T extends [infer U] ? (U extends [infer U] ? U : U) : never;
+
+const a: Unwrap<[[1]]> = 1;
+const b: Unwrap<[2]> = 2;
+
+type Inner<T> = T extends { a: infer U } ? (U extends { b: infer V } ? [U, V] : U) : never;
+
+const c: Inner<{ a: { b: 1 } }> = [{ b: 1 }, 1];
+const d: Inner<{ a: 2 }> = 2;
+
+export { }
@@ -0,0 +1,16 @@
+type ElementOf<T> = T extends Array<infer U> ? U : "none";
+
+declare const a: ElementOf<string>;
+const a1: "none" = a;
+const a2: number = a;
+
+declare const b: ElementOf<number[]>;
+const b1: number = b;
+
+type Second<T> = T extends [any, infer U] ? U : "none";
+
+declare const c: Second<[1]>;
+const c1: "none" = c;
+const c2: 1 = c;
+
+export { }
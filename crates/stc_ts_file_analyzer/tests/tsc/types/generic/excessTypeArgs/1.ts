@@ -0,0 +1,19 @@
+interface Box<T> {
+    value: T;
+}
+
+interface Pair<T, U = T> {
+    first: T;
+    second: U;
+}
+
+type Alias<T> = { value: T };
+
+declare const a: Box<number>;
+declare const b: Box<number, string>;
+declare const c: Pair<number>;
+declare const d: Pair<number, string>;
+declare const e: Pair<number, string, boolean>;
+declare const f: Alias<number, string>;
+
+export { }
@@ -0,0 +1,10 @@
+declare function one<T>(value: T): T;
+declare function withDefault<T, U = string>(value: T): U;
+
+one<number>(1);
+one<number, string>(1);
+withDefault<number>(1);
+withDefault<number, boolean>(1);
+withDefault<number, boolean, string>(1);
+
+export { }
@@ -0,0 +1,22 @@
+declare function make<T>(): T;
+declare function makeDefault<T extends object = { a: number }>(): T;
+declare function makeConstrained<T extends { a: string }>(): T;
+declare function handle<T>(handler: (value: T) => void): T;
+declare function handleDefault<T = string>(handler: (value: T) => void): T;
+
+const made = make();
+const s1: string = made;
+
+const withDefault = makeDefault();
+const n1: number = withDefault.a;
+
+const constrained = makeConstrained();
+const s2: string = constrained.a;
+
+const handled = handle(() => {});
+const s3: string = handled;
+
+const handledDefault = handleDefault(() => {});
+const s4: string = handledDefault;
+
+export { }
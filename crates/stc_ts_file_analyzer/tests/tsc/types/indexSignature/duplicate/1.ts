@@ -0,0 +1,21 @@
+interface Dup {
+    [key: string]: number;
+    [name: string]: number;
+}
+
+type DupNumber = {
+    [key: number]: string;
+    [index: number]: string;
+};
+
+interface Distinct {
+    [key: string]: string;
+    [index: number]: string;
+}
+
+type DistinctLit = {
+    [key: string]: number;
+    [index: number]: number;
+};
+
+export { }
@@ -0,0 +1,10 @@
+// @strictNullChecks: true
+
+declare const attrs: { [key: `data-${string}`]: string };
+
+const a: string = attrs["data-x"];
+attrs["data-y"] = "v";
+
+attrs.foo;
+
+export { }
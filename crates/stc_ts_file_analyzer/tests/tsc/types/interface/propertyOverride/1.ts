@@ -0,0 +1,16 @@
+interface A {
+    x: number;
+    y: string | number;
+    z?: boolean;
+}
+
+interface Incompatible extends A {
+    x: string;
+}
+
+interface Narrowed extends A {
+    y: string;
+    z: true;
+}
+
+export { }
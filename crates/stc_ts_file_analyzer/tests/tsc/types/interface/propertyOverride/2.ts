@@ -0,0 +1,17 @@
+interface A {
+    x: number;
+}
+
+interface B extends A {
+    y: string;
+}
+
+interface OptionalOverride extends B {
+    x?: number;
+}
+
+interface Compatible extends B {
+    x: 1 | 2;
+}
+
+export { }
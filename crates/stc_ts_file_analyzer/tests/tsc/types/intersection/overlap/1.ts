@@ -0,0 +1,24 @@
+type A = { kind: "a" } & { value: number };
+type B = { kind: "b" } & { name: string };
+
+declare const v: A | B;
+
+if (v.kind === "a") {
+    const a: A = v;
+    v.value;
+} else {
+    const b: B = v;
+    v.name;
+}
+
+declare const a: { kind: "a" } & { value: number };
+declare const b: { kind: "b" };
+declare const c: { kind: "a" };
+
+if (a === b) {
+}
+
+if (a === c) {
+}
+
+export { }
@@ -0,0 +1,9 @@
+type Ro<T> = { readonly [K in keyof T]: T[K] };
+type Flags = { [K in "a" | "b"]: boolean };
+
+Ro;
+const a = Flags;
+Ro();
+new Flags();
+
+export { }
@@ -0,0 +1,4 @@
+const a = Partial;
+Readonly();
+
+export { }
@@ -0,0 +1,13 @@
+interface Valid {
+    (a: string, b?: number, ...rest: boolean[]): void;
+    new (a: string, b?: number, c?: number): Valid;
+    method(a?: string, ...rest: number[]): void;
+}
+
+interface Invalid {
+    (a?: string, b: number): void;
+    new (a?: string, b: number): Invalid;
+    method(a?: string, b: number, c?: number): void;
+}
+
+export { }
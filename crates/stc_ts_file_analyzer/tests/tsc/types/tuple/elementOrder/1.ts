@@ -0,0 +1,7 @@
+type A = [string?, number];
+type B = [string, number?, boolean?];
+type C = [...string[], ...number[]];
+type D = [...string[], number?];
+type E = [string, ...number[], boolean];
+
+export { }
@@ -0,0 +1,4 @@
+type A<T extends unknown[], U extends unknown[]> = [...T, ...U];
+type B = [string, number?, ...boolean[]];
+
+export { }
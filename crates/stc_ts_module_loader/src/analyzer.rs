@@ -1,4 +1,6 @@
-use stc_ts_utils::imports::find_imports_in_comments;
+use rnode::NodeIdGenerator;
+use stc_ts_ast_rnode::RObjectLit;
+use stc_ts_utils::imports::{find_imports_in_comments, is_json_import};
 use swc_atoms::JsWord;
 use swc_common::{comments::Comments, Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
@@ -60,6 +62,14 @@ where
     }
 
     fn visit_import_decl(&mut self, import: &ImportDecl, _: &dyn Node) {
+        // JSON modules are not parsed as a typescript file.
+        if let Some(asserts) = &import.asserts {
+            let asserts: RObjectLit = NodeIdGenerator::invalid().make(asserts.clone());
+            if is_json_import(Some(&asserts)) {
+                return;
+            }
+        }
+
         self.deps.push(import.src.value.clone());
     }
 
@@ -51,6 +51,14 @@ where
         None
     }
 
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
     /// Returns the inserted value.
     pub fn insert(&mut self, key: K, mut value: V) -> V {
         value.make_clone_cheap();
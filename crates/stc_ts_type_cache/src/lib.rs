@@ -1,8 +1,10 @@
 #![allow(incomplete_features)]
 #![feature(specialization)]
 
-use stc_ts_types::{Id, Mapped, Ref, Type};
+use rustc_hash::FxHashMap;
+use stc_ts_types::{Conditional, Id, InferType, Mapped, Ref, Type, TypeParam};
 use stc_visit::{Visit, VisitWith};
+use swc_common::Span;
 
 use crate::{cache_map::CacheMap, cache_mode::CacheMode, key::CacheKey};
 
@@ -18,6 +20,17 @@ pub struct TypeCache {
 
     /// Key should be [Type::Arc] of [Type::TypeLit].
     pub keyof_type_lit: CacheMap<Type, Type, NoRevoke>,
+
+    /// Result of a distributive conditional type for each member of the
+    /// union, grouped by the span of the conditional type.
+    ///
+    /// Key is the conditional type with a member of the union as the check
+    /// type. Value is [None] if the result cannot be calculated, and
+    /// `Some(None)` if the member is removed.
+    ///
+    /// References in keys are resolved in the current module, so only
+    /// conditional types in the root scope should be stored.
+    pub distributive_conditional: FxHashMap<Span, CacheMap<Conditional, Option<Option<Type>>, NoFreeTypeParamInKey>>,
 }
 
 impl TypeCache {
@@ -60,3 +73,41 @@ impl Visit<Ref> for RefFinder {
         self.found = true;
     }
 }
+
+/// Refuses keys which contain type parameters, except the ones declared by
+/// `infer` in the key.
+#[derive(Debug)]
+pub struct NoFreeTypeParamInKey {}
+
+impl<K> CacheMode<K> for NoFreeTypeParamInKey
+where
+    K: CacheKey + VisitWith<FreeTypeParamFinder>,
+{
+    fn can_cache(key: &K) -> bool {
+        let mut v = FreeTypeParamFinder {
+            inferred: Default::default(),
+            found: false,
+        };
+        key.visit_with(&mut v);
+        !v.found
+    }
+}
+
+pub struct FreeTypeParamFinder {
+    inferred: Vec<Id>,
+    found: bool,
+}
+
+impl Visit<InferType> for FreeTypeParamFinder {
+    fn visit(&mut self, ty: &InferType) {
+        self.inferred.push(ty.type_param.name.clone());
+    }
+}
+
+impl Visit<TypeParam> for FreeTypeParamFinder {
+    fn visit(&mut self, param: &TypeParam) {
+        if !self.inferred.contains(&param.name) {
+            self.found = true;
+        }
+    }
+}
@@ -0,0 +1,21 @@
+type Flatten<T> = T extends readonly (infer U)[] ? Flatten<U> : T;
+
+type Key =
+    | "a0" | "a1" | "a2" | "a3" | "a4" | "a5" | "a6" | "a7" | "a8" | "a9"
+    | "b0" | "b1" | "b2" | "b3" | "b4" | "b5" | "b6" | "b7" | "b8" | "b9"
+    | "c0" | "c1" | "c2" | "c3" | "c4" | "c5" | "c6" | "c7" | "c8" | "c9"
+    | "d0" | "d1" | "d2" | "d3" | "d4" | "d5" | "d6" | "d7" | "d8" | "d9";
+
+type Nested = Key | Key[] | Key[][] | Key[][][] | (Key | Key[])[];
+
+type ExcludeA<T> = T extends "a0" | "a1" | "a2" | "a3" | "a4" ? never : T;
+
+export type A = Flatten<Nested>;
+export type B = ExcludeA<Flatten<Nested>>;
+export type C = ExcludeA<Key | Flatten<Nested>>;
+
+declare const a: A;
+declare const b: B;
+declare const c: C;
+
+export const values = [a, b, c];
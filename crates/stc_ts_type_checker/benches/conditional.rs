@@ -0,0 +1,58 @@
+#![feature(bench_black_box)]
+#![feature(box_syntax)]
+#![feature(test)]
+
+extern crate test;
+
+use std::{hint::black_box, path::PathBuf, sync::Arc};
+
+use stc_ts_builtin_types::Lib;
+use stc_ts_env::{Env, ModuleConfig};
+use stc_ts_file_analyzer::env::EnvFactory;
+use stc_ts_module_loader::resolvers::node::NodeResolver;
+use stc_ts_type_checker::Checker;
+use swc_common::{
+    errors::{ColorConfig, Handler},
+    FileName,
+};
+use swc_ecma_ast::EsVersion;
+use swc_ecma_parser::TsConfig;
+use test::Bencher;
+
+/// A large recursive conditional type distributed over a union.
+#[bench]
+fn distributive_conditional(b: &mut Bencher) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("benches")
+        .join("assets")
+        .join("distributiveConditional.ts");
+
+    ::testing::run_test2(false, |cm, _| {
+        let handler = Handler::with_tty_emitter(ColorConfig::Always, true, false, Some(cm.clone()));
+
+        let handler = Arc::new(handler);
+
+        b.iter(|| {
+            let mut checker = Checker::new(
+                cm.clone(),
+                handler.clone(),
+                Env::simple(
+                    Default::default(),
+                    EsVersion::latest(),
+                    ModuleConfig::None,
+                    &Lib::load("es2020.full"),
+                ),
+                TsConfig { ..Default::default() },
+                None,
+                Arc::new(NodeResolver),
+            );
+
+            let id = checker.check(Arc::new(FileName::Real(path.clone())));
+            black_box(checker.take_errors());
+            black_box(checker.take_dts(id));
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
@@ -0,0 +1,3 @@
+declare const loaded: { version: string; run(): void };
+
+export const plugin = loaded as typeof import("./test-2");
@@ -0,0 +1,3 @@
+export const version: string = "1.0.0";
+
+export function run(): void {}
@@ -1152,6 +1152,10 @@ pub struct TypeOrSpread {
     pub span: Span,
     pub spread: Option<Span>,
     pub ty: Box<Type>,
+    /// Label of the tuple element, if this is created by spreading a labeled
+    /// tuple.
+    #[not_type]
+    pub label: Option<RPat>,
 }
 
 pub trait TypeIterExt {}
@@ -1982,6 +1986,15 @@ impl Type {
         }
     }
 
+    pub fn is_bigint_lit(&self) -> bool {
+        match self.normalize() {
+            Type::Lit(LitType {
+                lit: RTsLit::BigInt(..), ..
+            }) => true,
+            _ => false,
+        }
+    }
+
     /// Returns true if `self` is a `boolean` or a boolean literal.
     pub fn is_bool(&self) -> bool {
         match self.normalize() {
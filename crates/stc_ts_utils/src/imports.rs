@@ -1,3 +1,4 @@
+use stc_ts_ast_rnode::{RExpr, RLit, RObjectLit, RProp, RPropName, RPropOrSpread};
 use swc_atoms::JsWord;
 use swc_common::{
     comments::{CommentKind, Comments},
@@ -65,3 +66,78 @@ where
 
     deps
 }
+
+/// Returns `true` if the import assertions contain `type: "json"`.
+///
+/// ```ts
+/// import data from "./data.json" assert { type: "json" };
+/// ```
+pub fn is_json_import(asserts: Option<&RObjectLit>) -> bool {
+    let asserts = match asserts {
+        Some(v) => v,
+        None => return false,
+    };
+
+    asserts.props.iter().any(|prop| match prop {
+        RPropOrSpread::Prop(box RProp::KeyValue(kv)) => {
+            let is_type_key = match &kv.key {
+                RPropName::Ident(i) => &*i.sym == "type",
+                RPropName::Str(s) => &*s.value == "type",
+                _ => false,
+            };
+
+            is_type_key
+                && match &*kv.value {
+                    RExpr::Lit(RLit::Str(s)) => &*s.value == "json",
+                    _ => false,
+                }
+        }
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rnode::NodeIdGenerator;
+    use stc_ts_ast_rnode::RObjectLit;
+    use swc_common::{sync::Lrc, FileName, SourceMap};
+    use swc_ecma_ast::{EsVersion, ModuleDecl, ModuleItem};
+    use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
+
+    use super::is_json_import;
+
+    fn is_json(src: &str) -> bool {
+        let cm: Lrc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let lexer = Lexer::new(
+            Syntax::Typescript(TsConfig {
+                import_assertions: true,
+                ..Default::default()
+            }),
+            EsVersion::Es2021,
+            StringInput::from(&*fm),
+            None,
+        );
+        let module = Parser::new_from(lexer).parse_module().unwrap();
+
+        let asserts: Option<RObjectLit> = match module.body.into_iter().next() {
+            Some(ModuleItem::ModuleDecl(ModuleDecl::Import(import))) => import.asserts.map(|v| NodeIdGenerator::invalid().make(v)),
+            _ => unreachable!("expected an import declaration"),
+        };
+
+        is_json_import(asserts.as_ref())
+    }
+
+    #[test]
+    fn json_assertion() {
+        assert!(is_json(r#"import data from "./data.json" assert { type: "json" };"#));
+        assert!(is_json(r#"import data from "./data.json" assert { "type": "json" };"#));
+    }
+
+    #[test]
+    fn non_json_assertion() {
+        assert!(!is_json(r#"import styles from "./styles.css" assert { type: "css" };"#));
+        assert!(!is_json(r#"import data from "./data.json" assert { kind: "json" };"#));
+        assert!(!is_json(r#"import data from "./data.json";"#));
+    }
+}
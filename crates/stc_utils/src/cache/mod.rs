@@ -35,6 +35,16 @@ where
     }
 }
 
+impl Freeze for bool {
+    #[inline]
+    fn is_clone_cheap(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn make_clone_cheap(&mut self) {}
+}
+
 impl<T> Freeze for Vec<T>
 where
     T: Freeze,